@@ -6,6 +6,22 @@ impl<'a> AstStyle<'a> {
     pub fn is_self_reference(&self) -> bool {
         matches!(self.elements.as_slice(), ["&"])
     }
+    /// Move the spans by `offset` bytes
+    #[inline]
+    pub fn shift(&mut self, offset: usize) {
+        self.span = self.span.start + offset..self.span.end + offset;
+        for v in &mut self.variants {
+            v.shift(offset)
+        }
+    }
+}
+
+impl<'a> ASTVariant<'a> {
+    /// Move the span by `offset` bytes
+    #[inline]
+    pub fn shift(&mut self, offset: usize) {
+        self.span = self.span.start + offset..self.span.end + offset;
+    }
 }
 
 impl<'a> AstGroup<'a> {
@@ -17,6 +33,15 @@ impl<'a> AstGroup<'a> {
             item.expand_with_head(styles, head)
         }
     }
+    /// Move the spans by `offset` bytes
+    #[inline]
+    pub fn shift(&mut self, offset: usize) {
+        self.span = self.span.start + offset..self.span.end + offset;
+        self.head.shift(offset);
+        for child in &mut self.children {
+            child.shift(offset)
+        }
+    }
 }
 
 impl<'a> Add<AstGroup<'a>> for AstStyle<'a> {
@@ -25,7 +50,7 @@ impl<'a> Add<AstGroup<'a>> for AstStyle<'a> {
     fn add(self, rhs: AstGroup<'a>) -> Self::Output {
         let mut head = self;
         head.add_assign(&rhs.head);
-        AstGroup { important: false, head, children: rhs.children }
+        AstGroup { important: false, span: rhs.span, head, children: rhs.children }
     }
 }
 
@@ -38,6 +63,14 @@ impl<'a> AstGroupItem<'a> {
             Self::Styled(rhs) => styles.push(rhs),
         }
     }
+    /// Move the spans by `offset` bytes
+    #[inline]
+    pub fn shift(&mut self, offset: usize) {
+        match self {
+            Self::Grouped(g) => g.shift(offset),
+            Self::Styled(s) => s.shift(offset),
+        }
+    }
 
     ///
     #[inline]
@@ -59,6 +92,8 @@ impl<'a> AstGroupItem<'a> {
 impl<'a> AddAssign<&AstStyle<'a>> for AstStyle<'a> {
     #[inline]
     fn add_assign(&mut self, rhs: &AstStyle<'a>) {
+        // errors of an expanded style point at the innermost class
+        self.span = rhs.span.clone();
         self.negative = merge_negative(self.negative, rhs.negative);
         self.variants.extend(rhs.variants.iter().cloned());
         self.arbitrary = self.arbitrary.or(self.arbitrary);
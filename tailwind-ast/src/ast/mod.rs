@@ -16,29 +16,70 @@ use nom::{
 };
 use std::{
     fmt::{Display, Formatter},
-    ops::{Add, AddAssign},
+    ops::{Add, AddAssign, Range},
 };
 
 /// Decompose a string into tailwind instructions
+///
+/// The span of every style is the byte range of its class in `input`.
 pub fn parse_tailwind(input: &str) -> Result<Vec<AstStyle>, Err<Error<&str>>> {
-    let rest = many0(tuple((multispace1, AstGroupItem::parse)));
-    let (head, groups) = match tuple((AstGroupItem::parse, rest))(input.trim()) {
-        Ok(o) => o.1,
-        Err(e) => return Err(e),
-    };
+    let trimmed = input.trim();
+    let (_, groups) = AstGroupItem::parse_many(trimmed)?;
     let mut out = vec![];
-    head.expand(&mut out);
-    for (_, g) in groups {
+    for mut g in groups {
+        g.shift(trimmed.as_ptr() as usize - input.as_ptr() as usize);
         g.expand(&mut out)
     }
     Ok(out)
 }
 
+/// Decompose a string into tailwind instructions, skipping the invalid classes
+///
+/// Returns the valid styles together with a diagnostic for every skipped class.
+pub fn parse_tailwind_recover(input: &str) -> (Vec<AstStyle<'_>>, Vec<AstDiagnostic>) {
+    let mut out = vec![];
+    let mut diagnostics = vec![];
+    let mut start = 0;
+    while start < input.len() {
+        let rest = &input[start..];
+        let trimmed = rest.trim_start();
+        if trimmed.is_empty() {
+            break;
+        }
+        start += rest.len() - trimmed.len();
+        let end = start + parse::token_end(trimmed);
+        match AstGroupItem::parse(trimmed) {
+            // the whole class must be consumed
+            Ok((next, mut item)) if trimmed.len() - next.len() == end - start => {
+                item.shift(start);
+                item.expand(&mut out);
+            }
+            _ => diagnostics.push(AstDiagnostic {
+                span: start..end,
+                message: format!("Invalid class `{}`", &input[start..end]),
+            }),
+        }
+        start = end;
+    }
+    (out, diagnostics)
+}
+
+/// A class skipped by [`parse_tailwind_recover`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AstDiagnostic {
+    /// Byte range in the input
+    pub span: Range<usize>,
+    /// What is wrong with the class
+    pub message: String,
+}
+
 /// `variant:ast-style(grouped)`
 #[derive(Clone, Debug, PartialEq)]
 pub struct AstGroup<'a> {
     /// Is a `!important` group
     pub important: bool,
+    /// Byte range in the input
+    pub span: Range<usize>,
     ///
     pub head: AstStyle<'a>,
     ///
@@ -59,6 +100,8 @@ pub enum AstGroupItem<'a> {
 pub struct AstStyle<'a> {
     /// Is a `!important` style
     pub important: bool,
+    /// Byte range in the input
+    pub span: Range<usize>,
     /// Is a negative style
     pub negative: bool,
     ///
@@ -96,6 +139,8 @@ pub struct AstImportant {}
 pub struct ASTVariant<'a> {
     /// `not-`
     pub not: bool,
+    /// Byte range in the input
+    pub span: Range<usize>,
     /// `::`
     pub pseudo: bool,
     /// `name-space`
@@ -6,14 +6,22 @@ impl<'a> AstGroup<'a> {
     /// `v:a?(a(a b))[!]?`
     #[inline]
     pub fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let (rest, (head, children, important)) =
-            tuple((AstStyle::parse, Self::parse_pair, opt(char('!'))))(input)?;
-        Ok((rest, Self { important: important.is_some(), head, children }))
+        let (rest, head) = AstStyle::parse(input)?;
+        let (rest, children) = Self::parse_pair(rest, input)?;
+        let (rest, important) = opt(char('!'))(rest)?;
+        let span = 0..offset(input, rest);
+        Ok((rest, Self { important: important.is_some(), span, head, children }))
     }
+    /// Children are spanned relative to `outer`
     #[inline]
-    fn parse_pair(input: &'a str) -> IResult<&'a str, Vec<AstGroupItem>> {
+    fn parse_pair(input: &'a str, outer: &'a str) -> IResult<&'a str, Vec<AstGroupItem<'a>>> {
         let (rest, paired) = delimited_paired('(', ')')(input)?;
-        Ok((rest, AstGroupItem::parse_many(paired.trim())?.1))
+        let paired = paired.trim();
+        let mut children = AstGroupItem::parse_many(paired)?.1;
+        for child in &mut children {
+            child.shift(offset(outer, paired));
+        }
+        Ok((rest, children))
     }
 }
 
@@ -23,13 +31,20 @@ impl<'a> AstGroupItem<'a> {
     pub fn parse(input: &'a str) -> IResult<&'a str, Self> {
         alt((Self::maybe_group, Self::maybe_style))(input)
     }
+    /// Whitespace separated items, spanned relative to `input`
     #[inline]
-    fn parse_many(input: &'a str) -> IResult<&'a str, Vec<Self>> {
-        let head = AstGroupItem::parse;
-        let rest = many0(tuple((multispace1, AstGroupItem::parse)));
-        let (rest, (first, other)) = tuple((head, rest))(input)?;
+    pub(crate) fn parse_many(input: &'a str) -> IResult<&'a str, Vec<Self>> {
+        let (mut rest, first) = AstGroupItem::parse(input)?;
         let mut out = vec![first];
-        out.extend(other.into_iter().map(|s| s.1));
+        while let Ok((start, _)) = multispace1::<_, Error<&str>>(rest) {
+            let (next, mut item) = match AstGroupItem::parse(start) {
+                Ok(o) => o,
+                Err(_) => break,
+            };
+            item.shift(offset(input, start));
+            out.push(item);
+            rest = next;
+        }
         Ok((rest, out))
     }
     fn maybe_group(input: &'a str) -> IResult<&'a str, Self> {
@@ -46,18 +61,25 @@ impl<'a> AstStyle<'a> {
     /// `v:v::-?a-a-a-[A]`
     #[inline]
     pub fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let (rest, (variants, negative, elements, arbitrary, important)) = tuple((
-            many0(ASTVariant::parse),
-            opt(char('-')),
-            opt(AstElements::parse),
-            opt(AstArbitrary::parse),
-            opt(char('!')),
-        ))(input)?;
+        let mut variants = vec![];
+        let mut rest = input;
+        while let Ok((next, mut variant)) = ASTVariant::parse(rest) {
+            variant.shift(offset(input, rest));
+            variants.push(variant);
+            rest = next;
+        }
+        let (rest, (negative, elements)) = tuple((opt(char('-')), opt(AstElements::parse)))(rest)?;
+        let (rest, arbitrary) = match elements {
+            Some(_) => opt(AstArbitrary::parse)(rest)?,
+            None => opt(AstArbitrary::parse_property)(rest)?,
+        };
+        let (rest, important) = opt(char('!'))(rest)?;
 
         Ok((
             rest,
             Self {
                 important: important.is_some(),
+                span: 0..offset(input, rest),
                 negative: negative.is_some(),
                 variants,
                 elements: elements.unwrap_or_default().elements,
@@ -105,6 +127,7 @@ impl<'a> ASTVariant<'a> {
         else {
             v.pseudo = Self::check_pseudo(&v.names.iter().map(<_>::as_ref).collect::<Vec<_>>());
         }
+        v.span = 0..offset(input, rest);
         Ok((rest, v))
     }
     /// `(not-)?(ALPHA)(-ALPHA)*`
@@ -117,7 +140,7 @@ impl<'a> ASTVariant<'a> {
         let not = opt(tuple((tag("not"), tag("-"))));
         let vs = separated_list0(tag("-"), alphanumeric1);
         let (rest, (not, names)) = tuple((not, vs))(input)?;
-        Ok((rest, Self { not: not.is_some(), pseudo: false, names }))
+        Ok((rest, Self { not: not.is_some(), span: 0..offset(input, rest), pseudo: false, names }))
     }
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/Pseudo-elements#index
     #[rustfmt::skip] #[inline]
@@ -141,10 +164,49 @@ impl<'a> AstArbitrary<'a> {
     /// `-[ANY+]`
     #[inline]
     pub fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let pair = delimited(char('['), take_till1(|c| c == ']'), char(']'));
-        let (rest, (_, arbitrary)) = tuple((char('-'), pair))(input)?;
+        let (rest, (_, arbitrary)) = tuple((char('-'), Self::parse_pair))(input)?;
         Ok((rest, Self { arbitrary }))
     }
+    /// `[property:value]`, only valid without elements
+    #[inline]
+    pub fn parse_property(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, arbitrary) = Self::parse_pair(input)?;
+        if !arbitrary.contains(':') {
+            return Err(Err::Error(Error::from_error_kind(input, ErrorKind::Verify)));
+        }
+        Ok((rest, Self { arbitrary }))
+    }
+    /// `[ANY+]`, nested brackets must be balanced, quoted strings and escaped chars are taken as is
+    #[inline]
+    fn parse_pair(input: &'a str) -> IResult<&'a str, &'a str> {
+        let (rest, _) = char('[')(input)?;
+        match arbitrary_end(rest) {
+            Some(end) if end > 0 => Ok((&rest[end + 1..], &rest[..end])),
+            _ => Err(Err::Error(Error::from_error_kind(input, ErrorKind::TakeUntil))),
+        }
+    }
+}
+
+/// Index of the `]` closing an arbitrary value
+fn arbitrary_end(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') if depth == 0 => return Some(i),
+            (None, ']') => depth -= 1,
+            _ => {}
+        }
+    }
+    None
 }
 
 impl AstReference {
@@ -156,6 +218,34 @@ impl AstReference {
     }
 }
 
+/// Length of the leading class, a group may contain whitespace inside its brackets or quotes
+pub(crate) fn token_end(input: &str) -> usize {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, c) if c.is_whitespace() && depth == 0 => return i,
+            _ => {}
+        }
+    }
+    input.len()
+}
+
+/// Byte offset of `inner` in `outer`, `inner` must be a sub slice of `outer`
+#[inline]
+fn offset(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
+
 fn delimited_paired(opening: char, closing: char) -> impl Fn(&str) -> IResult<&str, &str> {
     move |input: &str| {
         delimited(char(opening), take_until_unbalanced(opening, closing), char(closing))(input)
@@ -18,9 +18,20 @@ fn assert_arbitrary(input: &str, output: &str) {
 #[test]
 fn test_arbitrary() {
     assert_arbitrary("-[#FFF]", "#FFF");
-    assert_arbitrary("-[\\]]", "]");
+    // escapes are kept, they are resolved when printing css
+    assert_arbitrary("-[\\]]", "\\]");
     assert_arbitrary("-[']']", "']'");
     assert_arbitrary("-[[line-name],1fr,auto]", "[line-name],1fr,auto");
+    assert_arbitrary("-[repeat(auto-fill,minmax([a],1fr))]", "repeat(auto-fill,minmax([a],1fr))");
+    assert_arbitrary("-['[x]']", "'[x]'");
+    assert_arbitrary("-[\"]\"]]", "\"]\"");
+    assert_arbitrary("-[a\\_b]", "a\\_b");
+}
+
+#[test]
+#[should_panic]
+fn test_arbitrary_unbalanced() {
+    AstArbitrary::parse("-[[a]").unwrap();
 }
 
 #[test]
@@ -32,10 +43,10 @@ fn test_arbitrary_bad1() {
 #[test]
 fn test_variant() {
     let input = ASTVariant::parse("not-hover::").unwrap().1;
-    let output = ASTVariant { not: true, pseudo: true, names: vec!["hover"] };
+    let output = ASTVariant { span: 0..11, not: true, pseudo: true, names: vec!["hover"] };
     assert_eq!(input, output);
     let input = ASTVariant::parse("sm:").unwrap().1;
-    let output = ASTVariant { not: false, pseudo: false, names: vec!["sm"] };
+    let output = ASTVariant { span: 0..3, not: false, pseudo: false, names: vec!["sm"] };
     assert_eq!(input, output);
 }
 
@@ -43,6 +54,7 @@ fn test_variant() {
 fn test_style() {
     let input = AstStyle::parse("full").unwrap().1;
     let output = AstStyle {
+        span: 0..4,
         //
         negative: false,
         variants: vec![],
@@ -53,6 +65,7 @@ fn test_style() {
     assert_eq!(input, output);
     let input = AstStyle::parse("-top-1").unwrap().1;
     let output = AstStyle {
+        span: 0..6,
         //
         negative: true,
         variants: vec![],
@@ -63,17 +76,40 @@ fn test_style() {
     assert_eq!(input, output);
     let input = AstStyle::parse("not-hover:sm:text-red-[200/50]").unwrap().1;
     let output = AstStyle {
+        span: 0..30,
         //
         negative: false,
         variants: vec![
-            ASTVariant { not: true, pseudo: false, names: vec!["hover"] },
-            ASTVariant { not: false, pseudo: false, names: vec!["sm"] },
+            ASTVariant { span: 0..10, not: true, pseudo: false, names: vec!["hover"] },
+            ASTVariant { span: 10..13, not: false, pseudo: false, names: vec!["sm"] },
         ],
         elements: vec!["text", "red"],
         arbitrary: Some("200/50"),
         important: false,
     };
     assert_eq!(input, output);
+    let input = AstStyle::parse("hover:[--my-var:10px]").unwrap().1;
+    let output = AstStyle {
+        span: 0..21,
+        //
+        negative: false,
+        variants: vec![ASTVariant { span: 0..6, not: false, pseudo: false, names: vec!["hover"] }],
+        elements: vec![],
+        arbitrary: Some("--my-var:10px"),
+        important: false,
+    };
+    assert_eq!(input, output);
+}
+
+#[test]
+fn test_style_property() {
+    let input = AstStyle::parse("[mask-type:luminance]").unwrap().1;
+    assert_eq!(input.elements, Vec::<&str>::new());
+    assert_eq!(input.arbitrary, Some("mask-type:luminance"));
+    // a bare block without `:` is not a property
+    let input = AstStyle::parse("[luminance]").unwrap();
+    assert_eq!(input.0, "[luminance]");
+    assert_eq!(input.1.arbitrary, None);
 }
 
 // #[test]
@@ -86,7 +122,9 @@ fn test_style() {
 fn test_group() {
     let input = AstGroup::parse("w(full sm:auto)").unwrap().1;
     let output = AstGroup {
+        span: 0..15,
         head: AstStyle {
+            span: 0..1,
             negative: false,
             variants: vec![],
             elements: vec!["w"],
@@ -95,6 +133,7 @@ fn test_group() {
         },
         children: vec![
             Styled(AstStyle {
+                span: 2..6,
                 negative: false,
                 variants: vec![],
                 elements: vec!["full"],
@@ -102,8 +141,9 @@ fn test_group() {
                 important: false,
             }),
             Styled(AstStyle {
+                span: 7..14,
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["sm"] }],
+                variants: vec![ASTVariant { span: 7..10, not: false, pseudo: false, names: vec!["sm"] }],
                 elements: vec!["auto"],
                 arbitrary: None,
                 important: false,
@@ -114,7 +154,9 @@ fn test_group() {
     assert_eq!(input, output);
     let input = AstGroup::parse("rotate(-3 hover:6 md:(3 hover:-6))").unwrap().1;
     let output = AstGroup {
+        span: 0..34,
         head: AstStyle {
+            span: 0..6,
             negative: false,
             variants: vec![],
             elements: vec!["rotate"],
@@ -123,6 +165,7 @@ fn test_group() {
         },
         children: vec![
             Styled(AstStyle {
+                span: 7..9,
                 negative: true,
                 variants: vec![],
                 elements: vec!["3"],
@@ -130,22 +173,26 @@ fn test_group() {
                 important: false,
             }),
             Styled(AstStyle {
+                span: 10..17,
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"] }],
+                variants: vec![ASTVariant { span: 10..16, not: false, pseudo: false, names: vec!["hover"] }],
                 elements: vec!["6"],
                 arbitrary: None,
                 important: false,
             }),
             Grouped(AstGroup {
+                span: 18..33,
                 head: AstStyle {
+                    span: 18..21,
                     negative: false,
-                    variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["md"] }],
+                    variants: vec![ASTVariant { span: 18..21, not: false, pseudo: false, names: vec!["md"] }],
                     elements: vec![],
                     arbitrary: None,
                     important: false,
                 },
                 children: vec![
                     Styled(AstStyle {
+                        span: 22..23,
                         negative: false,
                         variants: vec![],
                         elements: vec!["3"],
@@ -153,8 +200,10 @@ fn test_group() {
                         important: false,
                     }),
                     Styled(AstStyle {
+                        span: 24..32,
                         negative: true,
                         variants: vec![ASTVariant {
+                            span: 24..30,
                             not: false,
                             pseudo: false,
                             names: vec!["hover"],
@@ -172,7 +221,9 @@ fn test_group() {
     assert_eq!(input, output);
     let input = AstGroup::parse("bg-blue-500(hover:& focus:& active:&)").unwrap().1;
     let output = AstGroup {
+        span: 0..37,
         head: AstStyle {
+            span: 0..11,
             negative: false,
             variants: vec![],
             elements: vec!["bg", "blue", "500"],
@@ -181,22 +232,25 @@ fn test_group() {
         },
         children: vec![
             Styled(AstStyle {
+                span: 12..19,
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["hover"] }],
+                variants: vec![ASTVariant { span: 12..18, not: false, pseudo: false, names: vec!["hover"] }],
                 elements: vec!["&"],
                 arbitrary: None,
                 important: false,
             }),
             Styled(AstStyle {
+                span: 20..27,
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["focus"] }],
+                variants: vec![ASTVariant { span: 20..26, not: false, pseudo: false, names: vec!["focus"] }],
                 elements: vec!["&"],
                 arbitrary: None,
                 important: false,
             }),
             Styled(AstStyle {
+                span: 28..36,
                 negative: false,
-                variants: vec![ASTVariant { not: false, pseudo: false, names: vec!["active"] }],
+                variants: vec![ASTVariant { span: 28..35, not: false, pseudo: false, names: vec!["active"] }],
                 elements: vec!["&"],
                 arbitrary: None,
                 important: false,
@@ -243,3 +297,26 @@ fn test_expand() {
         "bg-red-500 shadow-xs sm:bg-red-600 sm:shadow-sm md:bg-red-700 md:shadow-md lg:bg-red-800 lg:shadow-xl",
     );
 }
+
+#[test]
+fn test_span() {
+    let input = "  p-2 hover:(m-1 -mt-2)  w(full)";
+    let spans: Vec<_> = parse_tailwind(input).unwrap().into_iter().map(|s| s.span).collect();
+    assert_eq!(spans, vec![2..5, 13..16, 17..22, 27..31]);
+    assert_eq!(&input[17..22], "-mt-2");
+}
+
+#[test]
+fn test_recover() {
+    let input = "p-2 ]oops hover:(m-1 -mt-2) w-[1px)x";
+    let (styles, diagnostics) = parse_tailwind_recover(input);
+    let styles: Vec<_> = styles.iter().map(|s| (s.to_string(), s.span.clone())).collect();
+    assert_eq!(styles, vec![
+        ("p-2".to_string(), 0..3),
+        ("hover:m-1".to_string(), 17..20),
+        ("hover:-mt-2".to_string(), 21..26),
+    ]);
+    let spans: Vec<_> = diagnostics.iter().map(|d| d.span.clone()).collect();
+    assert_eq!(spans, vec![4..9, 28..36]);
+    assert_eq!(diagnostics[0].message, "Invalid class `]oops`");
+}
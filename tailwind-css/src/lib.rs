@@ -9,5 +9,5 @@ mod modules;
 mod systems;
 mod traits;
 
-pub use self::{modules::*, systems::*, traits::TailwindInstance};
+pub use self::{modules::*, systems::*, traits::{TailwindInstance, TailwindPlugin}};
 pub use tailwind_error::{Result, TailwindError, TailwindErrorKind};
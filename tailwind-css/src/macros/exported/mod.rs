@@ -2,11 +2,11 @@
 #[macro_export]
 macro_rules! css_attributes {
     () => {
-        CssAttributes::default()
+        $crate::CssAttributes::default()
     };
     ($($k:expr => $v:expr),* $(,)?) => {{
-        let mut css = CssAttributes::default();
-        $(crate::macros::sealed::css_insert!(css, $k, $v);)*
+        let mut css = $crate::CssAttributes::default();
+        $(css.insert($k.to_string(), $v.to_string());)*
         css
     }};
 }
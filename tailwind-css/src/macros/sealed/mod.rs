@@ -40,22 +40,5 @@ macro_rules! color_instance {
         }
     };
 }
-macro_rules! css_insert {
-    // not work!!!
-    // ($css:expr, "transform", $v:expr) => {
-    //     $css.transform($v.to_string());
-    // };
-    // ($css:expr, "filter", $v:expr) => {
-    //     $css.filter($v.to_string());
-    // };
-    // ($css:expr, "backdrop-filter", $v:expr) => {
-    //     $css.backdrop_filter($v.to_string());
-    // };
-    ($css:expr, $k:expr,  $v:expr) => {
-        $css.insert($k.to_string(), $v.to_string());
-    };
-}
-
 pub(crate) use color_instance;
-pub(crate) use css_insert;
 pub(crate) use keyword_instance;
@@ -0,0 +1,37 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindArbitraryProperty {
+    property: String,
+    value: TailwindArbitrary,
+}
+
+impl Display for TailwindArbitraryProperty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}:{}]", self.property, self.value.as_raw().replace(' ', "_"))
+    }
+}
+
+impl TailwindInstance for TailwindArbitraryProperty {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        css_attributes! {
+            &self.property => self.value.get_raw_properties()
+        }
+    }
+}
+
+impl TailwindArbitraryProperty {
+    /// <https://tailwindcss.com/docs/adding-custom-styles#arbitrary-properties>
+    pub fn parse(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let (property, value) = match arbitrary.as_raw().split_once(':') {
+            Some((p, v)) if Self::check_valid(p) && !v.trim().is_empty() => (p, v),
+            _ => return syntax_error!("Unknown arbitrary property: {}", arbitrary.get_class()),
+        };
+        Ok(Self { property: property.to_string(), value: TailwindArbitrary::from(value.trim()) })
+    }
+    /// `mask-type`, `--my-var`
+    pub fn check_valid(property: &str) -> bool {
+        !property.is_empty() && property.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+}
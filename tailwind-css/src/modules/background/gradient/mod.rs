@@ -1,63 +1,150 @@
 use super::*;
 
+#[derive(Clone, Debug)]
+enum GradientStop {
+    Color(TailwindColor),
+    Position(String),
+    ArbitraryPosition(TailwindArbitrary),
+}
+
 #[doc=include_str!("readme.md")]
 #[derive(Clone, Debug)]
 pub struct TailwindFrom {
-    color: TailwindColor,
+    kind: GradientStop,
 }
 
 #[doc=include_str!("readme.md")]
 #[derive(Clone, Debug)]
 pub struct TailwindVia {
-    color: TailwindColor,
+    kind: GradientStop,
 }
 
 #[doc=include_str!("readme.md")]
 #[derive(Clone, Debug)]
 pub struct TailwindTo {
-    color: TailwindColor,
+    kind: GradientStop,
+}
+
+impl Display for GradientStop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Color(c) => write!(f, "{}", c),
+            Self::Position(p) => write!(f, "{}", p),
+            Self::ArbitraryPosition(p) => p.write(f),
+        }
+    }
+}
+
+impl GradientStop {
+    /// `from-10%`, `to-[80%]`, otherwise a color
+    fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        match pattern {
+            [n] if n.ends_with('%') && n.trim_end_matches('%').parse::<f32>().is_ok() => Ok(Self::Position(n.to_string())),
+            [] if arbitrary.as_length_or_fraction().is_ok() => Ok(Self::ArbitraryPosition(TailwindArbitrary::new(arbitrary)?)),
+            _ => Ok(Self::Color(TailwindColor::parse(pattern, arbitrary)?)),
+        }
+    }
+    fn get_position(&self) -> Option<String> {
+        match self {
+            Self::Color(_) => None,
+            Self::Position(p) => Some(p.to_string()),
+            Self::ArbitraryPosition(p) => Some(p.get_properties()),
+        }
+    }
+}
+
+impl<T> From<T> for TailwindFrom
+where
+    T: Into<TailwindColor>,
+{
+    fn from(color: T) -> Self {
+        Self { kind: GradientStop::Color(color.into()) }
+    }
+}
+impl<T> From<T> for TailwindVia
+where
+    T: Into<TailwindColor>,
+{
+    fn from(color: T) -> Self {
+        Self { kind: GradientStop::Color(color.into()) }
+    }
+}
+impl<T> From<T> for TailwindTo
+where
+    T: Into<TailwindColor>,
+{
+    fn from(color: T) -> Self {
+        Self { kind: GradientStop::Color(color.into()) }
+    }
 }
-crate::macros::sealed::color_instance!(TailwindFrom);
-crate::macros::sealed::color_instance!(TailwindVia);
-crate::macros::sealed::color_instance!(TailwindTo);
 
 impl Display for TailwindFrom {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "from-{}", self.color)
+        write!(f, "from-{}", self.kind)
     }
 }
 impl Display for TailwindVia {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "via-{}", self.color)
+        write!(f, "via-{}", self.kind)
     }
 }
 impl Display for TailwindTo {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "to-{}", self.color)
+        write!(f, "to-{}", self.kind)
     }
 }
+
 impl TailwindInstance for TailwindFrom {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        let color = self.color.get_properties(ctx);
+        let color = match &self.kind {
+            GradientStop::Color(c) => c.get_properties(ctx),
+            position => return css_attributes! { "--tw-gradient-from-position" => position.get_position().unwrap_or_default() },
+        };
         css_attributes! {
-            "--tw-gradient-from" => color,
-            "--tw-gradient-stops" => format!("var(--tw-gradient-from),var(--tw-gradient-to,{color})", color=color)
+            "--tw-gradient-from" => format!("{} var(--tw-gradient-from-position,)", color),
+            "--tw-gradient-stops" => "var(--tw-gradient-from), var(--tw-gradient-to, transparent)"
         }
     }
 }
 impl TailwindInstance for TailwindVia {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        let color = self.color.get_properties(ctx);
+        let color = match &self.kind {
+            GradientStop::Color(c) => c.get_properties(ctx),
+            position => return css_attributes! { "--tw-gradient-via-position" => position.get_position().unwrap_or_default() },
+        };
+        // `--tw-gradient-to` starts as transparent in the preflight, so `to-*` wins in any order
         css_attributes! {
-            "--tw-gradient-stops" => format!("var(--tw-gradient-from),{color},var(--tw-gradient-to,{color})", color=color)
+            "--tw-gradient-stops" => format!("var(--tw-gradient-from), {} var(--tw-gradient-via-position,), var(--tw-gradient-to, transparent)", color)
         }
     }
 }
 impl TailwindInstance for TailwindTo {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        let color = self.color.get_properties(ctx);
+        let color = match &self.kind {
+            GradientStop::Color(c) => c.get_properties(ctx),
+            position => return css_attributes! { "--tw-gradient-to-position" => position.get_position().unwrap_or_default() },
+        };
         css_attributes! {
-            "--tw-gradient-to" => color
+            "--tw-gradient-to" => format!("{} var(--tw-gradient-to-position,)", color)
         }
     }
 }
+
+impl TailwindFrom {
+    /// <https://tailwindcss.com/docs/gradient-color-stops>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: GradientStop::parse(pattern, arbitrary)? })
+    }
+}
+impl TailwindVia {
+    /// <https://tailwindcss.com/docs/gradient-color-stops#middle-color>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: GradientStop::parse(pattern, arbitrary)? })
+    }
+}
+impl TailwindTo {
+    /// <https://tailwindcss.com/docs/gradient-color-stops#ending-color>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: GradientStop::parse(pattern, arbitrary)? })
+    }
+}
@@ -3,25 +3,90 @@ use super::*;
 #[doc=include_str!("readme.md")]
 #[derive(Clone, Debug)]
 pub struct TailwindBackgroundImage {
-    kind: AnchorPoint,
+    kind: BackgroundImage,
+}
+
+#[derive(Clone, Debug)]
+enum BackgroundImage {
+    None,
+    /// `(class, css direction)`
+    Linear(&'static str, &'static str),
+    Radial(Option<TailwindArbitrary>),
+    Conic(Option<TailwindArbitrary>),
+    Arbitrary(TailwindArbitrary),
 }
 
 impl Display for TailwindBackgroundImage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "bg-gradient-{}", self.kind.get_class())
+        match &self.kind {
+            BackgroundImage::None => write!(f, "bg-none"),
+            BackgroundImage::Linear(class, _) => write!(f, "bg-gradient-to-{}", class),
+            BackgroundImage::Radial(None) => write!(f, "bg-radial"),
+            BackgroundImage::Radial(Some(s)) => write!(f, "bg-radial-{}", s.get_class()),
+            BackgroundImage::Conic(None) => write!(f, "bg-conic"),
+            BackgroundImage::Conic(Some(s)) => write!(f, "bg-conic-{}", s.get_class()),
+            BackgroundImage::Arbitrary(s) => write!(f, "bg-{}", s.get_class()),
+        }
     }
 }
 
 impl TailwindInstance for TailwindBackgroundImage {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        // underscores stand for spaces, `bg-conic-[from_90deg]`
+        let shape = |s: &Option<TailwindArbitrary>| match s {
+            Some(s) => format!("{}, ", s.get_properties()),
+            None => String::new(),
+        };
+        let image = match &self.kind {
+            BackgroundImage::None => "none".to_string(),
+            BackgroundImage::Linear(_, direction) => format!("linear-gradient({}, var(--tw-gradient-stops))", direction),
+            BackgroundImage::Radial(s) => format!("radial-gradient({}var(--tw-gradient-stops))", shape(s)),
+            BackgroundImage::Conic(s) => format!("conic-gradient({}var(--tw-gradient-stops))", shape(s)),
+            BackgroundImage::Arbitrary(s) => s.get_properties(),
+        };
         css_attributes! {
-            "background-image" => self.kind.get_properties()
+            "background-image" => image
         }
     }
 }
 
 impl TailwindBackgroundImage {
+    /// <https://tailwindcss.com/docs/background-image#linear-gradients>
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        Ok(Self { kind: AnchorPoint::parse(pattern, arbitrary, false)? })
+        let kind = match pattern {
+            ["t"] => BackgroundImage::Linear("t", "to top"),
+            ["tr"] => BackgroundImage::Linear("tr", "to top right"),
+            ["r"] => BackgroundImage::Linear("r", "to right"),
+            ["br"] => BackgroundImage::Linear("br", "to bottom right"),
+            ["b"] => BackgroundImage::Linear("b", "to bottom"),
+            ["bl"] => BackgroundImage::Linear("bl", "to bottom left"),
+            ["l"] => BackgroundImage::Linear("l", "to left"),
+            ["tl"] => BackgroundImage::Linear("tl", "to top left"),
+            _ => return syntax_error!("Unknown gradient direction: {} + {}", pattern.join("-"), arbitrary.get_class()),
+        };
+        Ok(Self { kind })
+    }
+    /// `bg-radial`, `bg-radial-[circle_at_top]`
+    pub fn parse_radial(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: BackgroundImage::Radial(Self::parse_shape("radial", pattern, arbitrary)?) })
+    }
+    /// `bg-conic`, `bg-conic-[from_90deg]`
+    pub fn parse_conic(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: BackgroundImage::Conic(Self::parse_shape("conic", pattern, arbitrary)?) })
+    }
+    /// `bg-[url('/image.png')]`, `bg-[image:var(--image)]`
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: BackgroundImage::Arbitrary(TailwindArbitrary::new(arbitrary)?) })
+    }
+    /// `bg-none`
+    pub fn none() -> Self {
+        Self { kind: BackgroundImage::None }
+    }
+    fn parse_shape(id: &str, pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Option<TailwindArbitrary>> {
+        match pattern {
+            [] if arbitrary.is_none() => Ok(None),
+            [] => Ok(Some(TailwindArbitrary::new(arbitrary)?)),
+            _ => syntax_error!("Unknown {} gradient instructions: {}", id, pattern.join("-")),
+        }
     }
 }
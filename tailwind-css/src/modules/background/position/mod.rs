@@ -8,14 +8,14 @@ pub struct TailwindBackgroundPosition {
 
 impl Display for TailwindBackgroundPosition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "origin-{}", self.kind.get_class())
+        write!(f, "bg-{}", self.kind.get_class())
     }
 }
 
 impl TailwindInstance for TailwindBackgroundPosition {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
-            "transform-origin" => self.kind.get_properties()
+            "background-position" => self.kind.get_properties()
         }
     }
 }
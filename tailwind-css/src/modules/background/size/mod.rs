@@ -15,6 +15,7 @@ impl Display for TailwindBackgroundSize {
                 s @ ("auto" | "cover" | "contain") => write!(f, "bg-{}", s),
                 _ => write!(f, "bg-size-{}", s),
             },
+            StandardValue::Arbitrary(s) if s.get_hint().is_some() => s.write_class(f, "bg-"),
             StandardValue::Arbitrary(s) => s.write_class(f, "bg-size-"),
         }
     }
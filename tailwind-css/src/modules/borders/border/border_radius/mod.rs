@@ -18,6 +18,12 @@ enum RoundedKind {
     RoundedTR,
     RoundedBL,
     RoundedBR,
+    RoundedS,
+    RoundedE,
+    RoundedSS,
+    RoundedSE,
+    RoundedES,
+    RoundedEE,
 }
 
 impl Display for RoundedKind {
@@ -32,19 +38,25 @@ impl Display for RoundedKind {
             Self::RoundedTR => write!(f, "rounded-tr"),
             Self::RoundedBL => write!(f, "rounded-bl"),
             Self::RoundedBR => write!(f, "rounded-br"),
+            Self::RoundedS => write!(f, "rounded-s"),
+            Self::RoundedE => write!(f, "rounded-e"),
+            Self::RoundedSS => write!(f, "rounded-ss"),
+            Self::RoundedSE => write!(f, "rounded-se"),
+            Self::RoundedES => write!(f, "rounded-es"),
+            Self::RoundedEE => write!(f, "rounded-ee"),
         }
     }
 }
 
 impl Display for TailwindRounded {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}-[{}]", self.kind, self.size)
+        write!(f, "{}-[{}]", self.kind, self.size.get_properties())
     }
 }
 
 impl TailwindInstance for TailwindRounded {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        let size = format!("{:?}", self.size);
+        let size = self.size.get_properties();
         match self.kind {
             RoundedKind::Rounded => css_attributes! {
                 "border-radius" => &size
@@ -77,6 +89,26 @@ impl TailwindInstance for TailwindRounded {
             RoundedKind::RoundedBR => css_attributes! {
                 "border-bottom-right-radius" => &size,
             },
+            RoundedKind::RoundedS => css_attributes! {
+                "border-start-start-radius" => &size,
+                "border-end-start-radius" => &size,
+            },
+            RoundedKind::RoundedE => css_attributes! {
+                "border-start-end-radius" => &size,
+                "border-end-end-radius" => &size,
+            },
+            RoundedKind::RoundedSS => css_attributes! {
+                "border-start-start-radius" => &size,
+            },
+            RoundedKind::RoundedSE => css_attributes! {
+                "border-start-end-radius" => &size,
+            },
+            RoundedKind::RoundedES => css_attributes! {
+                "border-end-start-radius" => &size,
+            },
+            RoundedKind::RoundedEE => css_attributes! {
+                "border-end-end-radius" => &size,
+            },
         }
     }
 }
@@ -92,6 +124,12 @@ impl TailwindRounded {
             ["tr" | "9", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedTR, arbitrary),
             ["bl" | "3", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedBL, arbitrary),
             ["br" | "1", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedBR, arbitrary),
+            ["s", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedS, arbitrary),
+            ["e", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedE, arbitrary),
+            ["ss", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedSS, arbitrary),
+            ["se", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedSE, arbitrary),
+            ["es", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedES, arbitrary),
+            ["ee", rest @ ..] => Self::parse_inner(rest, RoundedKind::RoundedEE, arbitrary),
             _ => Self::parse_inner(pattern, RoundedKind::Rounded, arbitrary),
         }
     }
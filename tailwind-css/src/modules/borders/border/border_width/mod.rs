@@ -1,3 +1,95 @@
+use super::*;
+
 #[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindBorderWidth {
+    side: BorderSide,
+    kind: NumericValue,
+}
+
 #[derive(Copy, Clone, Debug)]
-pub struct TailwindBorderWidth {}
+enum BorderSide {
+    All,
+    X,
+    Y,
+    T,
+    R,
+    B,
+    L,
+    S,
+    E,
+}
+
+impl Display for BorderSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "border"),
+            Self::X => write!(f, "border-x"),
+            Self::Y => write!(f, "border-y"),
+            Self::T => write!(f, "border-t"),
+            Self::R => write!(f, "border-r"),
+            Self::B => write!(f, "border-b"),
+            Self::L => write!(f, "border-l"),
+            Self::S => write!(f, "border-s"),
+            Self::E => write!(f, "border-e"),
+        }
+    }
+}
+
+impl Display for TailwindBorderWidth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.side, self.kind)
+    }
+}
+
+impl TailwindInstance for TailwindBorderWidth {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let width = self.kind.get_properties(|f| format!("{}px", f));
+        let mut css = CssAttributes::default();
+        for property in self.properties() {
+            css.insert(*property, &width);
+        }
+        css
+    }
+}
+
+impl TailwindBorderWidth {
+    /// <https://tailwindcss.com/docs/border-width>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let (side, rest) = match pattern {
+            ["x", rest @ ..] => (BorderSide::X, rest),
+            ["y", rest @ ..] => (BorderSide::Y, rest),
+            ["t", rest @ ..] => (BorderSide::T, rest),
+            ["r", rest @ ..] => (BorderSide::R, rest),
+            ["b", rest @ ..] => (BorderSide::B, rest),
+            ["l", rest @ ..] => (BorderSide::L, rest),
+            ["s", rest @ ..] => (BorderSide::S, rest),
+            ["e", rest @ ..] => (BorderSide::E, rest),
+            _ => (BorderSide::All, pattern),
+        };
+        let kind = match rest {
+            [] if arbitrary.is_none() => NumericValue::from(1u32),
+            // `border-[#fff]` is a color
+            [] if arbitrary.as_length().is_err() => return syntax_error!("Unknown border-width: {}", arbitrary.get_class()),
+            _ => NumericValue::positive_parser("border-width", Self::check_valid)(rest, arbitrary)?,
+        };
+        Ok(Self { side, kind })
+    }
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-width#syntax>
+    pub fn check_valid(mode: &str) -> bool {
+        ["thin", "medium", "thick", "inherit", "initial", "revert", "unset"].contains(&mode)
+    }
+    fn properties(&self) -> &'static [&'static str] {
+        match self.side {
+            BorderSide::All => &["border-width"],
+            BorderSide::X => &["border-left-width", "border-right-width"],
+            BorderSide::Y => &["border-top-width", "border-bottom-width"],
+            BorderSide::T => &["border-top-width"],
+            BorderSide::R => &["border-right-width"],
+            BorderSide::B => &["border-bottom-width"],
+            BorderSide::L => &["border-left-width"],
+            BorderSide::S => &["border-inline-start-width"],
+            BorderSide::E => &["border-inline-end-width"],
+        }
+    }
+}
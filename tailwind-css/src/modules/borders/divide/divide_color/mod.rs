@@ -10,14 +10,20 @@ crate::macros::sealed::color_instance!(TailwindDivideColor);
 
 impl Display for TailwindDivideColor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ring-{}", self.color)
+        write!(f, "divide-{}", self.color)
     }
 }
 
 impl TailwindInstance for TailwindDivideColor {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
+    }
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
-            "--tw-ring-color" => self.color.get_properties(ctx)
+            "border-color" => self.color.get_properties(ctx)
         }
     }
 }
@@ -22,6 +22,12 @@ impl Display for TailwindDivideReverse {
 }
 
 impl TailwindInstance for TailwindDivideReverse {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
+    }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let class = match self.axis {
             true => "--tw-divide-x-reverse",
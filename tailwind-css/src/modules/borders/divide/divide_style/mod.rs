@@ -27,12 +27,11 @@ impl TailwindInstance for TailwindDivideStyle {
         false
     }
     fn selectors(&self, _: &TailwindBuilder) -> String {
-        // format!(".divide-{} > * + *", self.kind)
-        format!(".divide-{}>:not([hidden])~:not([hidden])", self.kind)
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
     }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
-            "divide-style" => self.kind
+            "border-style" => self.kind
         }
     }
 }
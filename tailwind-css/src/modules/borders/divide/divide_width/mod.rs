@@ -11,20 +11,29 @@ pub struct TailwindDivideWidth {
 
 impl Display for TailwindDivideWidth {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.axis.write_xy(f, "divide-", &self.kind)
+        self.axis.write_xy(f, "divide", &self.kind)
     }
 }
 
 impl TailwindInstance for TailwindDivideWidth {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
+    }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let width = self.kind.get_properties(|f| format!("{}px", f));
         match self.axis {
             AxisXY::X => css_attributes! {
-                "border-right-width" => format!("{}px", self.kind),
-                "border-left-width" => "0"
+                "--tw-divide-x-reverse" => "0",
+                "border-right-width" => format!("calc({} * var(--tw-divide-x-reverse))", width),
+                "border-left-width" => format!("calc({} * calc(1 - var(--tw-divide-x-reverse)))", width)
             },
             AxisXY::Y => css_attributes! {
-                "border-top-width" => "0",
-                "border-bottom-width" => format!("{}px", self.kind)
+                "--tw-divide-y-reverse" => "0",
+                "border-top-width" => format!("calc({} * calc(1 - var(--tw-divide-y-reverse)))", width),
+                "border-bottom-width" => format!("calc({} * var(--tw-divide-y-reverse))", width)
             },
             AxisXY::N => unreachable!(),
         }
@@ -34,7 +43,10 @@ impl TailwindInstance for TailwindDivideWidth {
 impl TailwindDivideWidth {
     /// https://tailwindcss.com/docs/divide-width
     pub fn parse(input: &[&str], arbitrary: &TailwindArbitrary, axis: bool) -> Result<Self> {
-        let kind = NumericValue::positive_parser("divide-width", Self::check_valid)(input, arbitrary)?;
+        let kind = match input {
+            [] if arbitrary.is_none() => NumericValue::from(1u32),
+            _ => NumericValue::positive_parser("divide-width", Self::check_valid)(input, arbitrary)?,
+        };
         Ok(Self { axis: AxisXY::from(axis), kind })
     }
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-style#syntax>
@@ -21,7 +21,8 @@ impl TailwindDivide {
             [s @ ("solid" | "dashed" | "dotted" | "double" | "none")] => TailwindDivideStyle::from(*s).boxed(),
             ["style", rest @ ..] => TailwindDivideStyle::parse(rest, arbitrary)?.boxed(),
             // https://tailwindcss.com/docs/divide-color
-            _ => return syntax_error!("Unknown divide instructions: {}", str.join("-")),
+            ["color", rest @ ..] => TailwindDivideColor::parse(rest, arbitrary)?.boxed(),
+            _ => TailwindDivideColor::parse(str, arbitrary)?.boxed(),
         };
         Ok(out)
     }
@@ -10,11 +10,39 @@ pub(crate) mod ring_width;
 pub struct TailwindRing {}
 
 impl TailwindRing {
+    /// Composed `box-shadow`, shared by rings and box shadows so that `shadow-md ring-2` work together
+    pub(crate) const BOX_SHADOW: &'static str =
+        "var(--tw-ring-offset-shadow, 0 0 #0000), var(--tw-ring-shadow, 0 0 #0000), var(--tw-shadow, 0 0 #0000)";
+    /// Parse the instructions starting with `ring`.
     pub fn adapt(str: &[&str], arbitrary: &TailwindArbitrary) -> Result<Box<dyn TailwindInstance>> {
         let out = match str {
+            // https://tailwindcss.com/docs/ring-width
+            [] if arbitrary.is_none() => TailwindRingWidth::parse(str, arbitrary)?.boxed(),
+            ["width", rest @ ..] => TailwindRingWidth::parse(rest, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/ring-width#inset-rings
+            ["inset"] => TailwindRingInset::default().boxed(),
             // https://tailwindcss.com/docs/ring-offset-width
-            ["offset", rest @ ..] => TailwindRingOffsetWidth::parse(rest, arbitrary)?.boxed(),
-            _ => return syntax_error!("Unknown ring instructions: {}", str.join("-")),
+            ["offset", rest @ ..] => Self::offset_adaptor(rest, arbitrary)?,
+            // https://tailwindcss.com/docs/ring-color
+            ["color", rest @ ..] => TailwindRingColor::parse(rest, arbitrary)?.boxed(),
+            // Flexible parsing pattern
+            [] if arbitrary.as_str().starts_with(|c: char| c.is_numeric()) => TailwindRingWidth::parse(str, arbitrary)?.boxed(),
+            [n] if n.starts_with(|c: char| c.is_numeric()) => TailwindRingWidth::parse(str, arbitrary)?.boxed(),
+            _ => TailwindRingColor::parse(str, arbitrary)?.boxed(),
+        };
+        Ok(out)
+    }
+    fn offset_adaptor(str: &[&str], arbitrary: &TailwindArbitrary) -> Result<Box<dyn TailwindInstance>> {
+        let out = match str {
+            // https://tailwindcss.com/docs/ring-offset-width
+            ["width", rest @ ..] => TailwindRingOffsetWidth::parse(rest, arbitrary)?.boxed(),
+            [] if arbitrary.is_none() => TailwindRingOffsetWidth::parse(str, arbitrary)?.boxed(),
+            [] if arbitrary.as_str().starts_with(|c: char| c.is_numeric()) =>
+                TailwindRingOffsetWidth::parse(str, arbitrary)?.boxed(),
+            [n] if n.starts_with(|c: char| c.is_numeric()) => TailwindRingOffsetWidth::parse(str, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/ring-offset-color
+            ["color", rest @ ..] => TailwindRingOffsetColor::parse(rest, arbitrary)?.boxed(),
+            _ => TailwindRingOffsetColor::parse(str, arbitrary)?.boxed(),
         };
         Ok(out)
     }
@@ -15,16 +15,15 @@ impl Display for TailwindRingOffsetWidth {
 impl TailwindInstance for TailwindRingOffsetWidth {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         css_attributes! {
-            "--tw-ring-offset-width" => self.kind,
-            "box-shadow" => "0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)"
+            "--tw-ring-offset-width" => self.kind.get_properties(|f| format!("{}px", f))
         }
     }
 }
 impl TailwindRingOffsetWidth {
-    /// <https://tailwindcss.com/docs/ring-width>
+    /// <https://tailwindcss.com/docs/ring-offset-width>
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
         let kind = match pattern {
-            [] => NumericValue::from(3u32),
+            [] if arbitrary.is_none() => NumericValue::from(3u32),
             _ => NumericValue::positive_parser("ring-offset-width", Self::check_valid)(pattern, arbitrary)?,
         };
         Ok(Self { kind })
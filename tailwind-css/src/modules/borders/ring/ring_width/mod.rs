@@ -10,15 +10,21 @@ pub struct TailwindRingWidth {
 
 impl Display for TailwindRingWidth {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ring-offset-{}", self.kind)
+        write!(f, "ring-{}", self.kind)
     }
 }
 
 impl TailwindInstance for TailwindRingWidth {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let width = self.kind.get_properties(|f| format!("{}px", f));
+        let ring = format!(
+            "var(--tw-ring-inset,) 0 0 0 calc({} + var(--tw-ring-offset-width, 0px)) var(--tw-ring-color, rgb(59 130 246 / 0.5))",
+            width
+        );
         css_attributes! {
-            "--tw-ring-offset-width" => self.kind,
-            "box-shadow" => "0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)"
+            "--tw-ring-offset-shadow" => "var(--tw-ring-inset,) 0 0 0 var(--tw-ring-offset-width, 0px) var(--tw-ring-offset-color, #fff)",
+            "--tw-ring-shadow" => ring,
+            "box-shadow" => TailwindRing::BOX_SHADOW
         }
     }
 }
@@ -26,8 +32,8 @@ impl TailwindRingWidth {
     /// <https://tailwindcss.com/docs/ring-width>
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
         let kind = match pattern {
-            [] => NumericValue::from(3u32),
-            _ => NumericValue::positive_parser("blur", Self::check_valid)(pattern, arbitrary)?,
+            [] if arbitrary.is_none() => NumericValue::from(3u32),
+            _ => NumericValue::positive_parser("ring-width", Self::check_valid)(pattern, arbitrary)?,
         };
         Ok(Self { kind })
     }
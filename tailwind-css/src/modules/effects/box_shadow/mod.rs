@@ -4,6 +4,8 @@ use super::*;
 #[derive(Clone, Debug)]
 pub struct TailwindShadow {
     kind: StandardValue,
+    /// Layers of the preset or of the arbitrary value
+    layers: Vec<ShadowLayer>,
     drop: Backdrop,
 }
 
@@ -21,12 +23,19 @@ impl Display for TailwindShadow {
 }
 
 impl TailwindInstance for TailwindShadow {
-    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        let shadow = match &self.kind {
-            // TODO: resolve color
-            StandardValue::Keyword(s) if self.drop.0 => ctx.effects.get_drop_shadow(s),
-            StandardValue::Keyword(s) => ctx.effects.get_box_shadow(s),
-            StandardValue::Arbitrary(s) => s.get_properties(),
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        if self.drop.0 {
+            let shadow = match &self.kind {
+                StandardValue::Keyword(_) => self.layers.iter().map(|s| format!("drop-shadow({})", s)).collect::<Vec<_>>().join(" "),
+                StandardValue::Arbitrary(s) if s.as_str().starts_with("drop-shadow(") => s.get_properties(),
+                StandardValue::Arbitrary(s) => format!("drop-shadow({})", s.get_properties()),
+            };
+            return self.drop.get_shadow(shadow);
+        }
+        let shadow = match self.layers.is_empty() {
+            // `shadow-[none]` has no color to replace
+            true => self.kind.get_properties(),
+            false => self.layers.iter().map(|s| s.get_colored("--tw-shadow-color")).collect::<Vec<_>>().join(", "),
         };
         self.drop.get_shadow(shadow)
     }
@@ -34,15 +43,26 @@ impl TailwindInstance for TailwindShadow {
 
 impl TailwindShadow {
     /// <https://tailwindcss.com/docs/box-shadow>
-    pub fn parse(input: &[&str], arbitrary: &TailwindArbitrary, drop: bool) -> Result<Self> {
-        let kind = match input {
-            [] if arbitrary.is_some() => StandardValue::parse_arbitrary(arbitrary)?,
-            _ => StandardValue::Keyword(input.join("-")),
+    ///
+    /// Unknown presets are an error, `shadow-foo` is not the `DEFAULT` shadow.
+    pub fn parse(input: &[&str], arbitrary: &TailwindArbitrary, drop: bool, ctx: &TailwindBuilder) -> Result<Self> {
+        if input.is_empty() && arbitrary.is_some() {
+            return Self::parse_arbitrary(arbitrary, drop);
+        }
+        let name = input.join("-");
+        let layers = match drop {
+            true => ctx.effects.get_drop_shadow_layers(&name)?,
+            false => ctx.effects.get_box_shadow_layers(&name)?,
         };
-        Ok(Self { kind, drop: Backdrop(drop) })
+        Ok(Self { layers: layers.to_vec(), kind: StandardValue::Keyword(name), drop: Backdrop(drop) })
     }
     /// <https://tailwindcss.com/docs/box-shadow#arbitrary-values>
     pub fn parse_arbitrary(arbitrary: &TailwindArbitrary, drop: bool) -> Result<Self> {
-        Ok(Self { kind: StandardValue::parse_arbitrary(arbitrary)?, drop: Backdrop(drop) })
+        let kind = StandardValue::parse_arbitrary(arbitrary)?;
+        let layers = match drop {
+            true => vec![],
+            false => ShadowLayer::parse_layers(&arbitrary.get_properties()),
+        };
+        Ok(Self { kind, layers, drop: Backdrop(drop) })
     }
 }
@@ -31,7 +31,7 @@ impl TailwindBlend {
     ///
     /// - https://developer.mozilla.org/zh-CN/docs/Web/CSS/blend-mode
     #[inline]
-    pub fn get_properties(&self) -> &str {
+    pub fn get_properties(&self) -> String {
         self.kind.get_properties()
     }
     pub fn check_valid(mode: &str) -> bool {
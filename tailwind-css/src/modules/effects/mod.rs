@@ -1,9 +1,9 @@
 pub use self::{
     box_shadow::TailwindShadow, mix_blend::TailwindBlend, mix_blend_bg::TailwindBackgroundBlend, opacity::TailwindOpacity,
-    shadow_color::TailwindShadowColor,
+    shadow_color::TailwindShadowColor, text_shadow::TailwindTextShadow,
 };
 use crate::{
-    css_attributes, Backdrop, CssAttributes, NumericValue, Result, StandardValue, TailwindArbitrary, TailwindBuilder,
+    css_attributes, Backdrop, CssAttributes, NumericValue, Result, ShadowLayer, StandardValue, TailwindArbitrary, TailwindBuilder,
     TailwindColor, TailwindInstance,
 };
 use std::{
@@ -15,3 +15,4 @@ mod mix_blend;
 mod mix_blend_bg;
 mod opacity;
 mod shadow_color;
+mod text_shadow;
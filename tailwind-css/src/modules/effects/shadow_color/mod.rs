@@ -4,20 +4,65 @@ use super::*;
 #[derive(Clone, Debug)]
 pub struct TailwindShadowColor {
     color: TailwindColor,
+    alpha: Option<u32>,
+    text: bool,
 }
 
-crate::macros::sealed::color_instance!(TailwindShadowColor);
+impl<T> From<T> for TailwindShadowColor
+where
+    T: Into<TailwindColor>,
+{
+    fn from(color: T) -> Self {
+        Self { color: color.into(), alpha: None, text: false }
+    }
+}
 
 impl Display for TailwindShadowColor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "shadow-{}", self.color)
+        if self.text {
+            write!(f, "text-")?;
+        }
+        write!(f, "shadow-{}", self.color)?;
+        if let Some(alpha) = self.alpha {
+            write!(f, "/{}", alpha)?;
+        }
+        Ok(())
     }
 }
 
 impl TailwindInstance for TailwindShadowColor {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        css_attributes! {
-            "--tw-shadow-color" => self.color.get_properties(ctx),
+        let color = match self.alpha {
+            Some(alpha) => format!("color-mix(in srgb, {} {}%, transparent)", self.color.get_properties(ctx), alpha),
+            None => self.color.get_properties(ctx),
+        };
+        // the shadow presets read the color through `var(--tw-shadow-color, ...)`, so the rule order does not matter
+        match self.text {
+            true => css_attributes! {
+                "--tw-text-shadow-color" => color
+            },
+            false => css_attributes! {
+                "--tw-shadow-color" => color
+            },
         }
     }
 }
+
+impl TailwindShadowColor {
+    /// <https://tailwindcss.com/docs/box-shadow-color>
+    pub fn parse(input: &[&str], arbitrary: &TailwindArbitrary, text: bool) -> Result<Self> {
+        // `shadow-blue-500/50`
+        if let Some((last, init)) = input.split_last() {
+            if let Some((weight, alpha)) = last.split_once('/') {
+                let alpha = TailwindArbitrary::from(alpha).as_integer()? as u32;
+                let input = init.iter().copied().chain(Some(weight)).collect::<Vec<_>>();
+                return Ok(Self { color: TailwindColor::parse(&input, arbitrary)?, alpha: Some(alpha), text });
+            }
+        }
+        Ok(Self { color: TailwindColor::parse(input, arbitrary)?, alpha: None, text })
+    }
+    /// <https://tailwindcss.com/docs/box-shadow-color#arbitrary-values>
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary, text: bool) -> Result<Self> {
+        Ok(Self { color: TailwindColor::parse_arbitrary(arbitrary)?, alpha: None, text })
+    }
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindTextShadow {
+    kind: StandardValue,
+    /// Layers of the preset or of the arbitrary value
+    layers: Vec<ShadowLayer>,
+}
+
+impl Display for TailwindTextShadow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            StandardValue::Keyword(s) if s.is_empty() => write!(f, "text-shadow"),
+            StandardValue::Keyword(s) => write!(f, "text-shadow-{}", s),
+            StandardValue::Arbitrary(s) => s.write_class(f, "text-shadow-"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindTextShadow {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let shadow = match self.layers.is_empty() {
+            true => self.kind.get_properties(),
+            false => self.layers.iter().map(|s| s.get_colored("--tw-text-shadow-color")).collect::<Vec<_>>().join(", "),
+        };
+        css_attributes! {
+            "--tw-text-shadow" => shadow,
+            "text-shadow" => "var(--tw-text-shadow)",
+        }
+    }
+}
+
+impl TailwindTextShadow {
+    /// <https://tailwindcss.com/docs/text-shadow>
+    pub fn parse(input: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Self> {
+        if input.is_empty() && arbitrary.is_some() {
+            return Self::parse_arbitrary(arbitrary);
+        }
+        let name = input.join("-");
+        let layers = ctx.effects.get_text_shadow_layers(&name)?.to_vec();
+        Ok(Self { kind: StandardValue::Keyword(name), layers })
+    }
+    /// <https://tailwindcss.com/docs/text-shadow#using-a-custom-value>
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = StandardValue::parse_arbitrary(arbitrary)?;
+        Ok(Self { kind, layers: ShadowLayer::parse_layers(&arbitrary.get_properties()) })
+    }
+}
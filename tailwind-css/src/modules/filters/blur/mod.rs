@@ -16,7 +16,7 @@ impl Display for TailwindBlur {
 impl TailwindInstance for TailwindBlur {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.px.get_properties(|f| format!("{}px", f));
-        self.backdrop.get_filter(format!("blur({})", n))
+        self.backdrop.get_filter("blur", n)
     }
 }
 
@@ -17,7 +17,7 @@ impl Display for TailwindBrightness {
 impl TailwindInstance for TailwindBrightness {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("brightness({})", n))
+        self.backdrop.get_filter("brightness", n)
     }
 }
 
@@ -17,7 +17,7 @@ impl Display for TailwindContrast {
 impl TailwindInstance for TailwindContrast {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("contrast({})", n))
+        self.backdrop.get_filter("contrast", n)
     }
 }
 
@@ -16,7 +16,7 @@ impl Display for TailwindGrayscale {
 impl TailwindInstance for TailwindGrayscale {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("grayscale({})", n))
+        self.backdrop.get_filter("grayscale", n)
     }
 }
 
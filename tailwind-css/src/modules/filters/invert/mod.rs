@@ -16,7 +16,7 @@ impl Display for TailwindInvert {
 impl TailwindInstance for TailwindInvert {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("invert({})", n))
+        self.backdrop.get_filter("invert", n)
     }
 }
 
@@ -2,7 +2,7 @@ use std::fmt::{Debug, Display, Formatter};
 
 use tailwind_error::Result;
 
-use crate::{CssAttributes, NumericValue, TailwindArbitrary, TailwindBuilder, TailwindInstance};
+use crate::{CssAttributes, NumericValue, TailwindArbitrary, TailwindBuilder, TailwindInstance, TailwindRing};
 
 pub use self::{
     blur::TailwindBlur, brightness::TailwindBrightness, contrast::TailwindContrast, grayscale::TailwindGrayscale,
@@ -38,14 +38,32 @@ impl Backdrop {
             },
         }
     }
-    pub fn get_filter<T>(&self, value: T) -> CssAttributes
+    /// The `filter` every filter utility writes, each function is read from its own variable
+    pub(crate) const FILTER: &'static str = "var(--tw-blur,) var(--tw-brightness,) var(--tw-contrast,) var(--tw-grayscale,) \
+                                             var(--tw-hue-rotate,) var(--tw-invert,) var(--tw-saturate,) var(--tw-sepia,) \
+                                             var(--tw-drop-shadow,)";
+    /// The `backdrop-filter` every backdrop filter utility writes
+    pub(crate) const BACKDROP_FILTER: &'static str = "var(--tw-backdrop-blur,) var(--tw-backdrop-brightness,) \
+                                                      var(--tw-backdrop-contrast,) var(--tw-backdrop-grayscale,) \
+                                                      var(--tw-backdrop-hue-rotate,) var(--tw-backdrop-invert,) \
+                                                      var(--tw-backdrop-opacity,) var(--tw-backdrop-saturate,) \
+                                                      var(--tw-backdrop-sepia,)";
+    /// Set `--tw-{name}` to `{name}({value})` and compose it into the filter
+    pub fn get_filter<T>(&self, name: &str, value: T) -> CssAttributes
     where
         T: Into<String>,
     {
+        let function = format!("{}({})", name, value.into());
         let mut css = CssAttributes::default();
         match self.0 {
-            true => css.insert("backdrop-filter", value.into()),
-            false => css.insert("filter", value.into()),
+            true => {
+                css.insert(format!("--tw-backdrop-{}", name), function);
+                css.insert("backdrop-filter", Self::BACKDROP_FILTER);
+            },
+            false => {
+                css.insert(format!("--tw-{}", name), function);
+                css.insert("filter", Self::FILTER);
+            },
         }
         css
     }
@@ -53,12 +71,14 @@ impl Backdrop {
     where
         T: Into<String>,
     {
-        let mut css = CssAttributes::default();
         match self.0 {
-            true => css.insert("backdrop-filter", format!("opacity({})", value.into())),
-            false => css.insert("opacity", value.into()),
+            true => self.get_filter("opacity", value),
+            false => {
+                let mut css = CssAttributes::default();
+                css.insert("opacity", value.into());
+                css
+            },
         }
-        css
     }
     pub fn get_shadow<T>(&self, value: T) -> CssAttributes
     where
@@ -66,8 +86,14 @@ impl Backdrop {
     {
         let mut css = CssAttributes::default();
         match self.0 {
-            true => css.insert("filter", value.into()),
-            false => css.insert("box-shadow", value.into()),
+            true => {
+                css.insert("--tw-drop-shadow", value.into());
+                css.insert("filter", Self::FILTER);
+            },
+            false => {
+                css.insert("--tw-shadow", value.into());
+                css.insert("box-shadow", TailwindRing::BOX_SHADOW);
+            },
         }
         css
     }
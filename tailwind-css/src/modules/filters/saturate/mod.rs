@@ -17,7 +17,7 @@ impl Display for TailwindSaturate {
 impl TailwindInstance for TailwindSaturate {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("saturate({})", n))
+        self.backdrop.get_filter("saturate", n)
     }
 }
 
@@ -16,7 +16,7 @@ impl Display for TailwindSepia {
 impl TailwindInstance for TailwindSepia {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let n = self.percent.get_properties(|f| format!("{}%", f));
-        self.backdrop.get_filter(format!("sepia({})", n))
+        self.backdrop.get_filter("sepia", n)
     }
 }
 
@@ -0,0 +1,100 @@
+use super::*;
+
+const INPUTS: &[&str] = &[
+    "[type='text']",
+    "input:where(:not([type]))",
+    "[type='email']",
+    "[type='url']",
+    "[type='password']",
+    "[type='number']",
+    "[type='date']",
+    "[type='datetime-local']",
+    "[type='month']",
+    "[type='search']",
+    "[type='tel']",
+    "[type='time']",
+    "[type='week']",
+    "textarea",
+    "select",
+];
+const CHECKS: &[&str] = &["[type='checkbox']", "[type='radio']"];
+const FIELDS: &[Form] = &[Form::Input, Form::Textarea, Form::Select];
+const TOGGLES: &[Form] = &[Form::Checkbox, Form::Radio];
+
+impl TailwindForm {
+    /// Select arrow, `{stroke}` is the url encoded border color
+    pub(super) const CHEVRON: &'static str = "data:image/svg+xml,%3csvg xmlns='http://www.w3.org/2000/svg' fill='none' \
+                                              viewBox='0 0 20 20'%3e%3cpath stroke='{stroke}' stroke-linecap='round' \
+                                              stroke-linejoin='round' stroke-width='1.5' d='M6 8l4 4 4-4'/%3e%3c/svg%3e";
+    pub(super) const RULES: [FormRule; 13] = [
+        (
+            FIELDS,
+            INPUTS,
+            "",
+            "appearance:none;background-color:#fff;border-color:{border};border-width:1px;border-radius:0;\
+             padding:.5rem .75rem;font-size:1rem;line-height:1.5rem;--tw-shadow:0 0 #0000",
+        ),
+        (
+            FIELDS,
+            INPUTS,
+            ":focus",
+            "outline:2px solid transparent;outline-offset:2px;--tw-ring-offset-width:0px;--tw-ring-offset-color:#fff;\
+             --tw-ring-color:{focus};\
+             --tw-ring-offset-shadow:var(--tw-ring-inset,) 0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color);\
+             --tw-ring-shadow:var(--tw-ring-inset,) 0 0 0 calc(1px + var(--tw-ring-offset-width)) var(--tw-ring-color);\
+             box-shadow:var(--tw-ring-offset-shadow),var(--tw-ring-shadow),var(--tw-shadow);border-color:{focus}",
+        ),
+        (&[Form::Input, Form::Textarea], &["input", "textarea"], "::placeholder", "color:{border};opacity:1"),
+        (
+            &[Form::Select],
+            &["select"],
+            "",
+            "background-image:url(\"{chevron}\");background-position:right .5rem center;background-repeat:no-repeat;\
+             background-size:1.5em 1.5em;padding-right:2.5rem;print-color-adjust:exact",
+        ),
+        (
+            TOGGLES,
+            CHECKS,
+            "",
+            "appearance:none;padding:0;print-color-adjust:exact;display:inline-block;vertical-align:middle;\
+             background-origin:border-box;user-select:none;flex-shrink:0;height:1rem;width:1rem;color:{focus};\
+             background-color:#fff;border-color:{border};border-width:1px;--tw-shadow:0 0 #0000",
+        ),
+        (&[Form::Checkbox], &["[type='checkbox']"], "", "border-radius:0"),
+        (&[Form::Radio], &["[type='radio']"], "", "border-radius:100%"),
+        (
+            TOGGLES,
+            CHECKS,
+            ":focus",
+            "outline:2px solid transparent;outline-offset:2px;--tw-ring-offset-width:2px;--tw-ring-offset-color:#fff;\
+             --tw-ring-color:{focus};\
+             --tw-ring-offset-shadow:var(--tw-ring-inset,) 0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color);\
+             --tw-ring-shadow:var(--tw-ring-inset,) 0 0 0 calc(2px + var(--tw-ring-offset-width)) var(--tw-ring-color);\
+             box-shadow:var(--tw-ring-offset-shadow),var(--tw-ring-shadow),var(--tw-shadow)",
+        ),
+        (
+            TOGGLES,
+            CHECKS,
+            ":checked",
+            "border-color:transparent;background-color:currentColor;background-size:100% 100%;\
+             background-position:center;background-repeat:no-repeat",
+        ),
+        (
+            &[Form::Checkbox],
+            &["[type='checkbox']"],
+            ":checked",
+            "background-image:url(\"data:image/svg+xml,%3csvg viewBox='0 0 16 16' fill='white' \
+             xmlns='http://www.w3.org/2000/svg'%3e%3cpath d='M12.207 4.793a1 1 0 010 1.414l-5 5a1 1 0 01-1.414 0l-2-2a1 1 0 \
+             011.414-1.414L6.5 9.086l4.293-4.293a1 1 0 011.414 0z'/%3e%3c/svg%3e\")",
+        ),
+        (
+            &[Form::Radio],
+            &["[type='radio']"],
+            ":checked",
+            "background-image:url(\"data:image/svg+xml,%3csvg viewBox='0 0 16 16' fill='white' \
+             xmlns='http://www.w3.org/2000/svg'%3e%3ccircle cx='8' cy='8' r='3'/%3e%3c/svg%3e\")",
+        ),
+        (TOGGLES, CHECKS, ":checked:hover", "border-color:transparent;background-color:currentColor"),
+        (TOGGLES, CHECKS, ":checked:focus", "border-color:transparent;background-color:currentColor"),
+    ];
+}
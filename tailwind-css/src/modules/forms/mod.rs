@@ -0,0 +1,107 @@
+use crate::*;
+use std::fmt::{Debug, Display, Formatter};
+use tailwind_error::Result;
+
+mod builtin;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Form {
+    Input,
+    Textarea,
+    Select,
+    Checkbox,
+    Radio,
+}
+
+/// `(applies to, element selectors of the base strategy, state, declarations)`
+///
+/// Declarations may use `{border}`, `{focus}` and `{chevron}`, which are filled from the palette
+type FormRule = (&'static [Form], &'static [&'static str], &'static str, &'static str);
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindForm {
+    kind: Form,
+}
+
+impl Display for Form {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input => write!(f, "input"),
+            Self::Textarea => write!(f, "textarea"),
+            Self::Select => write!(f, "select"),
+            Self::Checkbox => write!(f, "checkbox"),
+            Self::Radio => write!(f, "radio"),
+        }
+    }
+}
+
+impl Display for TailwindForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "form-{}", self.kind)
+    }
+}
+
+impl TailwindInstance for TailwindForm {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        let mut css = CssAttributes::default();
+        for (_, _, _, declarations) in self.rules().filter(|rule| rule.2.is_empty()) {
+            let declarations = Self::fill_colors(declarations, ctx);
+            for declaration in declarations.split(';') {
+                if let Some((key, value)) = declaration.split_once(':') {
+                    css.insert(key, value);
+                }
+            }
+        }
+        css
+    }
+    fn additional(&self, ctx: &TailwindBuilder) -> String {
+        let class = ctx.prefixed(&self.id());
+        let mut out = String::new();
+        for (_, _, state, declarations) in self.rules().filter(|rule| !rule.2.is_empty()) {
+            out.push_str(&format!(".{}{}{{{}}}", class, state, Self::fill_colors(declarations, ctx)));
+        }
+        out
+    }
+}
+
+impl TailwindForm {
+    /// <https://github.com/tailwindlabs/tailwindcss-forms#using-classes-to-style>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            ["input"] => Form::Input,
+            ["textarea"] => Form::Textarea,
+            ["select"] => Form::Select,
+            ["checkbox"] => Form::Checkbox,
+            ["radio"] => Form::Radio,
+            _ => return syntax_error!("Unknown form instructions: {} + {}", pattern.join("-"), arbitrary.get_class()),
+        };
+        Ok(Self { kind })
+    }
+    /// All rules of the base strategy, keyed by element selectors
+    pub fn get_base(ctx: &TailwindBuilder) -> String {
+        let mut out = String::new();
+        for (_, selectors, state, declarations) in Self::RULES {
+            let selectors = selectors.iter().map(|s| format!("{}{}", s, state)).collect::<Vec<_>>();
+            out.push_str(&format!("{}{{{}}}\n", selectors.join(","), Self::fill_colors(declarations, ctx)));
+        }
+        out
+    }
+    fn rules(&self) -> impl Iterator<Item = &'static FormRule> + '_ {
+        Self::RULES.iter().filter(move |rule| rule.0.contains(&self.kind))
+    }
+    fn fill_colors(declarations: &str, ctx: &TailwindBuilder) -> String {
+        let hex = |name: &str, weight: u32, fallback: &str| match ctx.palettes.try_get_color(name, weight) {
+            Ok(c) => format!("#{:02x}{:02x}{:02x}", (255.0 * c.red) as u8, (255.0 * c.green) as u8, (255.0 * c.blue) as u8),
+            Err(_) => fallback.to_string(),
+        };
+        let border = hex("gray", 500, "#6b7280");
+        declarations
+            .replace("{chevron}", &Self::CHEVRON.replace("{stroke}", &border.replace('#', "%23")))
+            .replace("{border}", &border)
+            .replace("{focus}", &hex("blue", 600, "#2563eb"))
+    }
+}
@@ -1,11 +1,16 @@
 use super::*;
 
-pub use self::{bottom::TailwindBottom, inset::TailwindInset, left::TailwindLeft, right::TailwindRight, top::TailwindTop};
+pub use self::{
+    bottom::TailwindBottom, end::TailwindEnd, inset::TailwindInset, left::TailwindLeft, right::TailwindRight, start::TailwindStart,
+    top::TailwindTop,
+};
 
 mod bottom;
+mod end;
 mod inset;
 mod left;
 mod right;
+mod start;
 mod top;
 
 pub(crate) fn get_kind_px_full_auto_fact(
@@ -0,0 +1,76 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindClipPath {
+    kind: ClipPath,
+}
+
+#[derive(Clone, Debug)]
+enum ClipPath {
+    None,
+    Circle(Option<TailwindArbitrary>),
+    Ellipse(Option<TailwindArbitrary>),
+    Inset(Option<TailwindArbitrary>),
+    Polygon(TailwindArbitrary),
+    Arbitrary(TailwindArbitrary),
+}
+
+impl Display for TailwindClipPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let shape = |f: &mut Formatter<'_>, name: &str, s: &Option<TailwindArbitrary>| match s {
+            Some(s) => write!(f, "clip-{}-{}", name, s.get_class()),
+            None => write!(f, "clip-{}", name),
+        };
+        match &self.kind {
+            ClipPath::None => write!(f, "clip-none"),
+            ClipPath::Circle(s) => shape(f, "circle", s),
+            ClipPath::Ellipse(s) => shape(f, "ellipse", s),
+            ClipPath::Inset(s) => shape(f, "inset", s),
+            ClipPath::Polygon(s) => s.write_class(f, "clip-polygon-"),
+            ClipPath::Arbitrary(s) => s.write_class(f, "clip-path-"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindClipPath {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        // underscores stand for spaces, `clip-inset-[0_round_8px]`
+        let shape = |s: &Option<TailwindArbitrary>, default: &str| match s {
+            Some(s) => s.get_properties(),
+            None => default.to_string(),
+        };
+        let path = match &self.kind {
+            ClipPath::None => "none".to_string(),
+            ClipPath::Circle(s) => format!("circle({})", shape(s, "50%")),
+            ClipPath::Ellipse(s) => format!("ellipse({})", shape(s, "50% 50%")),
+            ClipPath::Inset(s) => format!("inset({})", shape(s, "0")),
+            ClipPath::Polygon(s) => format!("polygon({})", s.get_properties()),
+            ClipPath::Arbitrary(s) => s.get_properties(),
+        };
+        css_attributes! {
+            "-webkit-clip-path" => path,
+            "clip-path" => path,
+        }
+    }
+}
+
+impl TailwindClipPath {
+    /// `clip-circle`, `clip-inset-[10px]`, `clip-polygon-[50%_0,100%_100%,0_100%]`
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let shape = || match arbitrary.is_none() {
+            true => Ok(None),
+            false => TailwindArbitrary::new(arbitrary).map(Some),
+        };
+        let kind = match pattern {
+            ["none"] => ClipPath::None,
+            ["circle"] => ClipPath::Circle(shape()?),
+            ["ellipse"] => ClipPath::Ellipse(shape()?),
+            ["inset"] => ClipPath::Inset(shape()?),
+            ["polygon"] if arbitrary.is_some() => ClipPath::Polygon(TailwindArbitrary::new(arbitrary)?),
+            [] | ["path"] if arbitrary.is_some() => ClipPath::Arbitrary(TailwindArbitrary::new(arbitrary)?),
+            _ => return syntax_error!("Unknown clip-path instructions: {} + {}", pattern.join("-"), arbitrary.get_class()),
+        };
+        Ok(Self { kind })
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindMaskImage {
+    kind: MaskImage,
+}
+
+#[derive(Clone, Debug)]
+enum MaskImage {
+    None,
+    /// `(class, css direction)`
+    Linear(&'static str, &'static str),
+    Radial(Option<TailwindArbitrary>),
+    Arbitrary(TailwindArbitrary),
+}
+
+impl Display for TailwindMaskImage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            MaskImage::None => write!(f, "mask-none"),
+            MaskImage::Linear(class, _) => write!(f, "mask-linear-to-{}", class),
+            MaskImage::Radial(None) => write!(f, "mask-radial"),
+            MaskImage::Radial(Some(s)) => write!(f, "mask-radial-{}", s.get_class()),
+            MaskImage::Arbitrary(s) => s.write_class(f, "mask-image-"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindMaskImage {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        // the opaque part of the mask stays visible, the transparent part fades out
+        let image = match &self.kind {
+            MaskImage::None => "none".to_string(),
+            MaskImage::Linear(_, direction) => format!("linear-gradient({}, black, transparent)", direction),
+            MaskImage::Radial(None) => "radial-gradient(black, transparent)".to_string(),
+            MaskImage::Radial(Some(s)) => {
+                format!("radial-gradient({}, black, transparent)", s.get_properties())
+            },
+            MaskImage::Arbitrary(s) => s.get_properties(),
+        };
+        css_attributes! {
+            "-webkit-mask-image" => image,
+            "mask-image" => image,
+        }
+    }
+}
+
+impl TailwindMaskImage {
+    /// `mask-linear-to-b`
+    pub fn parse_linear(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            ["t"] => MaskImage::Linear("t", "to top"),
+            ["tr"] => MaskImage::Linear("tr", "to top right"),
+            ["r"] => MaskImage::Linear("r", "to right"),
+            ["br"] => MaskImage::Linear("br", "to bottom right"),
+            ["b"] => MaskImage::Linear("b", "to bottom"),
+            ["bl"] => MaskImage::Linear("bl", "to bottom left"),
+            ["l"] => MaskImage::Linear("l", "to left"),
+            ["tl"] => MaskImage::Linear("tl", "to top left"),
+            _ => return syntax_error!("Unknown mask direction: {} + {}", pattern.join("-"), arbitrary.get_class()),
+        };
+        Ok(Self { kind })
+    }
+    /// `mask-radial`, `mask-radial-[circle_at_center]`
+    pub fn parse_radial(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            [] if arbitrary.is_none() => MaskImage::Radial(None),
+            [] => MaskImage::Radial(Some(TailwindArbitrary::new(arbitrary)?)),
+            _ => return syntax_error!("Unknown mask-radial instructions: {}", pattern.join("-")),
+        };
+        Ok(Self { kind })
+    }
+    /// `mask-[url(mask.svg)]`
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: MaskImage::Arbitrary(TailwindArbitrary::new(arbitrary)?) })
+    }
+    /// `mask-none`
+    pub fn none() -> Self {
+        Self { kind: MaskImage::None }
+    }
+}
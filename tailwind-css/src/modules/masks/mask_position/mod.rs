@@ -0,0 +1,30 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindMaskPosition {
+    kind: AnchorPoint,
+}
+
+impl Display for TailwindMaskPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "mask-position-{}", self.kind.get_class())
+    }
+}
+
+impl TailwindInstance for TailwindMaskPosition {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let position = self.kind.get_properties();
+        css_attributes! {
+            "-webkit-mask-position" => position,
+            "mask-position" => position,
+        }
+    }
+}
+
+impl TailwindMaskPosition {
+    /// `mask-center`, `mask-left-top`, `mask-position-[center_top_1rem]`
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: AnchorPoint::parse(pattern, arbitrary, true)? })
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Debug, Clone)]
+pub struct TailwindMaskRepeat {
+    kind: StandardValue,
+}
+
+impl<T> From<T> for TailwindMaskRepeat
+where
+    T: Into<String>,
+{
+    fn from(input: T) -> Self {
+        Self { kind: StandardValue::from(input.into()) }
+    }
+}
+
+impl Display for TailwindMaskRepeat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            StandardValue::Keyword(s) => match s.as_str() {
+                "repeat" => write!(f, "mask-repeat"),
+                "no-repeat" => write!(f, "mask-no-repeat"),
+                "repeat-x" => write!(f, "mask-repeat-x"),
+                "repeat-y" => write!(f, "mask-repeat-y"),
+                _ => write!(f, "mask-repeat-{}", s),
+            },
+            StandardValue::Arbitrary(s) => s.write_class(f, "mask-repeat-"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindMaskRepeat {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let repeat = self.kind.get_properties();
+        css_attributes! {
+            "-webkit-mask-repeat" => repeat,
+            "mask-repeat" => repeat,
+        }
+    }
+}
+
+impl TailwindMaskRepeat {
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/mask-repeat>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            [] if arbitrary.is_none() => StandardValue::from("repeat"),
+            ["none"] => StandardValue::from("no-repeat"),
+            ["x"] => StandardValue::from("repeat-x"),
+            ["y"] => StandardValue::from("repeat-y"),
+            _ => StandardValue::parser("mask-repeat", &Self::check_valid)(pattern, arbitrary)?,
+        };
+        Ok(Self { kind })
+    }
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/mask-repeat#syntax>
+    pub fn check_valid(mode: &str) -> bool {
+        let set = BTreeSet::from_iter(vec![
+            "inherit",
+            "initial",
+            "no-repeat",
+            "repeat",
+            "repeat-x",
+            "repeat-y",
+            "revert",
+            "round",
+            "space",
+            "unset",
+        ]);
+        set.contains(mode)
+    }
+}
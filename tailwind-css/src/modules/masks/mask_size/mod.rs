@@ -0,0 +1,51 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Debug, Clone)]
+pub struct TailwindMaskSize {
+    kind: StandardValue,
+}
+
+impl<T> From<T> for TailwindMaskSize
+where
+    T: Into<String>,
+{
+    fn from(input: T) -> Self {
+        Self { kind: StandardValue::from(input.into()) }
+    }
+}
+
+impl Display for TailwindMaskSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            StandardValue::Keyword(s) => match s.as_str() {
+                s @ ("auto" | "cover" | "contain") => write!(f, "mask-{}", s),
+                _ => write!(f, "mask-size-{}", s),
+            },
+            StandardValue::Arbitrary(s) => s.write_class(f, "mask-size-"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindMaskSize {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let size = self.kind.get_properties();
+        css_attributes! {
+            "-webkit-mask-size" => size,
+            "mask-size" => size,
+        }
+    }
+}
+
+impl TailwindMaskSize {
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/mask-size>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = StandardValue::parser("mask-size", &Self::check_valid)(pattern, arbitrary)?;
+        Ok(Self { kind })
+    }
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/mask-size#syntax>
+    pub fn check_valid(mode: &str) -> bool {
+        let set = BTreeSet::from_iter(vec!["auto", "contain", "cover", "inherit", "initial", "revert", "unset"]);
+        set.contains(mode)
+    }
+}
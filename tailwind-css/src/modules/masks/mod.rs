@@ -0,0 +1,18 @@
+pub use self::{
+    clip_path::TailwindClipPath, mask_image::TailwindMaskImage, mask_position::TailwindMaskPosition,
+    mask_repeat::TailwindMaskRepeat, mask_size::TailwindMaskSize,
+};
+use crate::{
+    AnchorPoint, CssAttributes, Result, StandardValue, TailwindArbitrary, TailwindBuilder, TailwindInstance, css_attributes,
+    syntax_error,
+};
+use std::{
+    collections::BTreeSet,
+    fmt::{Debug, Display, Formatter},
+};
+
+mod clip_path;
+mod mask_image;
+mod mask_position;
+mod mask_repeat;
+mod mask_size;
@@ -1,13 +1,16 @@
 #![allow(non_upper_case_globals)]
 #![allow(clippy::enum_variant_names)]
 mod accessibility;
+mod arbitrary;
 mod background;
 mod borders;
 mod effects;
 mod filters;
 mod flexbox;
+mod forms;
 mod interactivity;
 mod layouts;
+mod masks;
 mod sizing;
 mod spacing;
 mod svg;
@@ -17,8 +20,8 @@ mod transition;
 mod typography;
 
 pub use self::{
-    accessibility::*, background::*, borders::*, effects::*, filters::*, flexbox::*, interactivity::*, layouts::*, sizing::*,
-    spacing::*, svg::*, tables::*, transforms::*, transition::*, typography::*,
+    accessibility::*, arbitrary::*, background::*, borders::*, effects::*, filters::*, flexbox::*, forms::*, interactivity::*,
+    layouts::*, masks::*, sizing::*, spacing::*, svg::*, tables::*, transforms::*, transition::*, typography::*,
 };
 
 use crate::*;
@@ -1,8 +1,23 @@
 use super::*;
 
 impl SizingUnit {
+    /// <https://tailwindcss.com/docs/max-width>
+    const MAX_WIDTH: [(&'static str, &'static str); 12] = [
+        ("xs", "20rem"),
+        ("sm", "24rem"),
+        ("md", "28rem"),
+        ("lg", "32rem"),
+        ("xl", "36rem"),
+        ("2xl", "42rem"),
+        ("3xl", "48rem"),
+        ("4xl", "56rem"),
+        ("5xl", "64rem"),
+        ("6xl", "72rem"),
+        ("7xl", "80rem"),
+        ("prose", "65ch"),
+    ];
     pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        let px = |x| Ok(Self::Length(LengthUnit::em(x)));
+        let rem = |x| Ok(Self::Length(LengthUnit::rem(x)));
         match pattern {
             ["min"] => Ok(Self::Min),
             ["max"] => Ok(Self::Max),
@@ -10,14 +25,35 @@ impl SizingUnit {
             ["full"] => Ok(Self::Full),
             ["fit"] => Ok(Self::Fit),
             ["screen"] => Ok(Self::Screen),
-            ["0"] => px(0.0),
-            ["px"] => px(1.0),
+            ["dvw"] => Ok(Self::Viewport("dvw")),
+            ["svw"] => Ok(Self::Viewport("svw")),
+            ["lvw"] => Ok(Self::Viewport("lvw")),
+            ["dvh"] => Ok(Self::Viewport("dvh")),
+            ["svh"] => Ok(Self::Viewport("svh")),
+            ["lvh"] => Ok(Self::Viewport("lvh")),
+            ["0"] => rem(0.0),
+            ["px"] => Ok(Self::Length(LengthUnit::px(1.0))),
             [n] => Self::parse_arbitrary(&TailwindArbitrary::from(*n)),
             [] => Self::parse_arbitrary(arbitrary),
             _ => syntax_error!("Unknown sizing instructions: {}", pattern.join("-")),
         }
     }
+    pub fn parse_max_width(pattern: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Self> {
+        match pattern {
+            ["none"] => Ok(Self::None),
+            ["screen", name] => Ok(Self::Breakpoint(name.to_string(), ctx.screens.try_get_width(name)?)),
+            [name] => match Self::MAX_WIDTH.iter().find(|(n, _)| n == name) {
+                Some((name, value)) => Ok(Self::Named(name, value)),
+                None => Self::parse(pattern, arbitrary),
+            },
+            _ => Self::parse(pattern, arbitrary),
+        }
+    }
     pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        const FUNCTIONS: [&str; 5] = ["calc(", "min(", "max(", "clamp(", "var("];
+        if FUNCTIONS.iter().any(|f| arbitrary.as_str().starts_with(f)) {
+            return Ok(Self::Arbitrary(TailwindArbitrary::new(arbitrary)?));
+        }
         Self::maybe_fraction(arbitrary).or_else(|_| Self::maybe_no_unit(arbitrary)).or_else(|_| Self::maybe_length(arbitrary))
     }
     #[inline]
@@ -26,7 +62,7 @@ impl SizingUnit {
     }
     #[inline]
     fn maybe_no_unit(arbitrary: &TailwindArbitrary) -> Result<Self> {
-        let rem = |x| Ok(Self::Length(LengthUnit::em(x)));
+        let rem = |x| Ok(Self::Length(LengthUnit::rem(x)));
         rem(arbitrary.as_float()? / 4.0)
     }
     #[inline]
@@ -42,8 +78,8 @@ impl TailwindSizing {
         Ok(Self { kind: TailwindSizingKind::Width, size: SizingUnit::parse(pattern, arbitrary)? })
     }
     #[inline]
-    pub fn parse_width_max(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        Ok(Self { kind: TailwindSizingKind::MaxWidth, size: SizingUnit::parse(pattern, arbitrary)? })
+    pub fn parse_width_max(pattern: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Self> {
+        Ok(Self { kind: TailwindSizingKind::MaxWidth, size: SizingUnit::parse_max_width(pattern, arbitrary, ctx)? })
     }
     #[inline]
     pub fn parse_width_min(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
@@ -55,10 +91,19 @@ impl TailwindSizing {
     }
     #[inline]
     pub fn parse_height_max(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
-        Ok(Self { kind: TailwindSizingKind::MaxHeight, size: SizingUnit::parse(pattern, arbitrary)? })
+        let size = match pattern {
+            ["none"] => SizingUnit::None,
+            _ => SizingUnit::parse(pattern, arbitrary)?,
+        };
+        Ok(Self { kind: TailwindSizingKind::MaxHeight, size })
     }
     #[inline]
     pub fn parse_height_min(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
         Ok(Self { kind: TailwindSizingKind::MinHeight, size: SizingUnit::parse(pattern, arbitrary)? })
     }
+    /// <https://tailwindcss.com/docs/size>
+    #[inline]
+    pub fn parse_size(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: TailwindSizingKind::Size, size: SizingUnit::parse(pattern, arbitrary)? })
+    }
 }
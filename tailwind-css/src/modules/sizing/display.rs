@@ -3,14 +3,19 @@ use super::*;
 impl Display for SizingUnit {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::None => write!(f, "none"),
             Self::Min => write!(f, "min"),
             Self::Max => write!(f, "max"),
             Self::Fit => write!(f, "fit"),
             Self::Auto => write!(f, "auto"),
             Self::Full => write!(f, "full"),
             Self::Screen => write!(f, "screen"),
+            Self::Viewport(unit) => write!(f, "{}", unit),
+            Self::Named(name, _) => write!(f, "{}", name),
+            Self::Breakpoint(name, _) => write!(f, "screen-{}", name),
             Self::Fraction(numerator, denominator) => write!(f, "{}/{}", numerator, denominator),
-            Self::Length(x) => write!(f, "[{}]", x),
+            Self::Length(x) => write!(f, "[{}]", x.get_properties()),
+            Self::Arbitrary(x) => x.write(f),
         }
     }
 }
@@ -22,14 +27,19 @@ impl SizingUnit {
             false => "vh",
         };
         match self {
+            Self::None => "none".to_string(),
             Self::Min => "min-content".to_string(),
             Self::Max => "max-content".to_string(),
             Self::Fit => "fit-content".to_string(),
             Self::Auto => "auto".to_string(),
             Self::Full => "100%".to_string(),
             Self::Screen => format!("100{}", is_width),
-            Self::Fraction(numerator, denominator) => format!("{}%", *numerator as f32 / *denominator as f32),
-            Self::Length(x) => format!("{}", x),
+            Self::Viewport(unit) => format!("100{}", unit),
+            Self::Named(_, value) => value.to_string(),
+            Self::Breakpoint(_, width) => format!("{}px", width),
+            Self::Fraction(numerator, denominator) => format!("{}%", 100.0 * *numerator as f32 / *denominator as f32),
+            Self::Length(x) => x.get_properties(),
+            Self::Arbitrary(x) => x.get_properties(),
         }
     }
 }
@@ -43,6 +53,21 @@ impl Display for TailwindSizingKind {
             Self::Height => f.write_str("h"),
             Self::MinHeight => f.write_str("min-h"),
             Self::MaxHeight => f.write_str("max-h"),
+            Self::Size => f.write_str("size"),
+        }
+    }
+}
+
+impl TailwindSizingKind {
+    fn properties(&self) -> &'static [&'static str] {
+        match self {
+            Self::Width => &["width"],
+            Self::MinWidth => &["min-width"],
+            Self::MaxWidth => &["max-width"],
+            Self::Height => &["height"],
+            Self::MinHeight => &["min-height"],
+            Self::MaxHeight => &["max-height"],
+            Self::Size => &["width", "height"],
         }
     }
 }
@@ -55,10 +80,10 @@ impl Display for TailwindSizing {
 
 impl TailwindInstance for TailwindSizing {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        let class = self.kind.to_string();
-        let width = self.size.get_attribute(true);
-        css_attributes! {
-            class => width
+        let mut css = CssAttributes::default();
+        for property in self.kind.properties() {
+            css.insert(*property, self.size.get_attribute(property.ends_with("width")));
         }
+        css
     }
 }
@@ -11,22 +11,32 @@ enum TailwindSizingKind {
     Height,
     MinHeight,
     MaxHeight,
+    Size,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 enum SizingUnit {
+    None,
     Min,
     Max,
     Fit,
     Auto,
     Full,
     Screen,
+    /// `dvh`, `svh`, `lvh` and their width counterparts
+    Viewport(&'static str),
+    /// Named `max-w-*` scale, e.g. `max-w-prose`
+    Named(&'static str, &'static str),
+    /// `max-w-screen-*`, the min-width of the breakpoint in px
+    Breakpoint(String, usize),
     Fraction(usize, usize),
     Length(LengthUnit),
+    /// `calc(...)`, `min(...)`, `var(...)` and other css functions
+    Arbitrary(TailwindArbitrary),
 }
 
 #[doc = include_str!("sizing.md")]
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct TailwindSizing {
     kind: TailwindSizingKind,
     size: SizingUnit,
@@ -20,7 +20,11 @@ impl Display for TailwindMargin {
 impl TailwindInstance for TailwindMargin {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let mut out = CssAttributes::default();
-        self.axis.write_attributes(&mut out, self.size.get_properties());
+        let size = match self.size {
+            SpacingSize::Standard(_) => self.size.get_properties(),
+            _ => self.negative.get_properties(&self.size.get_properties()),
+        };
+        self.axis.write_attributes(&mut out, size);
         out
     }
 }
@@ -35,8 +39,10 @@ impl TailwindMargin {
             ["mr", rest @ ..] => (SpacingAxis::new("mr", &["margin-right"]), rest),
             ["mt", rest @ ..] => (SpacingAxis::new("mt", &["margin-top"]), rest),
             ["mb", rest @ ..] => (SpacingAxis::new("mb", &["margin-bottom"]), rest),
+            ["ms", rest @ ..] => (SpacingAxis::new("ms", &["margin-inline-start"]), rest),
+            ["me", rest @ ..] => (SpacingAxis::new("me", &["margin-inline-end"]), rest),
             ["mx", rest @ ..] => (SpacingAxis::new("mx", &["margin-left", "margin-right"]), rest),
-            ["my", rest @ ..] => (SpacingAxis::new("my", &["margin"]), rest),
+            ["my", rest @ ..] => (SpacingAxis::new("my", &["margin-top", "margin-bottom"]), rest),
             _ => return syntax_error!("Unknown margin axis"),
         };
         let size = SpacingSize::parse(rest, arbitrary, &Self::check_valid)?;
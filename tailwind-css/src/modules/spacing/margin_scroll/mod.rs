@@ -20,7 +20,11 @@ impl Display for TailwindScrollMargin {
 impl TailwindInstance for TailwindScrollMargin {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let mut out = CssAttributes::default();
-        self.axis.write_attributes(&mut out, self.size.get_properties());
+        let size = match self.size {
+            SpacingSize::Standard(_) => self.size.get_properties(),
+            _ => self.negative.get_properties(&self.size.get_properties()),
+        };
+        self.axis.write_attributes(&mut out, size);
         out
     }
 }
@@ -35,8 +39,10 @@ impl TailwindScrollMargin {
             ["mr", rest @ ..] => (SpacingAxis::new("scroll-mr", &["scroll-margin-right"]), rest),
             ["mt", rest @ ..] => (SpacingAxis::new("scroll-mt", &["scroll-margin-top"]), rest),
             ["mb", rest @ ..] => (SpacingAxis::new("scroll-mb", &["scroll-margin-bottom"]), rest),
+            ["ms", rest @ ..] => (SpacingAxis::new("scroll-ms", &["scroll-margin-inline-start"]), rest),
+            ["me", rest @ ..] => (SpacingAxis::new("scroll-me", &["scroll-margin-inline-end"]), rest),
             ["mx", rest @ ..] => (SpacingAxis::new("scroll-mx", &["scroll-margin-left", "scroll-margin-right"]), rest),
-            ["my", rest @ ..] => (SpacingAxis::new("scroll-my", &["scroll-margin"]), rest),
+            ["my", rest @ ..] => (SpacingAxis::new("scroll-my", &["scroll-margin-top", "scroll-margin-bottom"]), rest),
             _ => return syntax_error!("Unknown scroll-margin axis"),
         };
         let size = SpacingSize::parse(rest, arbitrary, &Self::check_valid)?;
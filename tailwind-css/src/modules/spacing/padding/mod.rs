@@ -35,8 +35,10 @@ impl TailwindPadding {
             ["pr", rest @ ..] => (SpacingAxis::new("pr", &["padding-right"]), rest),
             ["pt", rest @ ..] => (SpacingAxis::new("pt", &["padding-top"]), rest),
             ["pb", rest @ ..] => (SpacingAxis::new("pb", &["padding-bottom"]), rest),
+            ["ps", rest @ ..] => (SpacingAxis::new("ps", &["padding-inline-start"]), rest),
+            ["pe", rest @ ..] => (SpacingAxis::new("pe", &["padding-inline-end"]), rest),
             ["px", rest @ ..] => (SpacingAxis::new("px", &["padding-left", "padding-right"]), rest),
-            ["py", rest @ ..] => (SpacingAxis::new("py", &["padding"]), rest),
+            ["py", rest @ ..] => (SpacingAxis::new("py", &["padding-top", "padding-bottom"]), rest),
             _ => return syntax_error!("Unknown padding axis"),
         };
         let size = SpacingSize::parse(rest, arbitrary, &Self::check_valid)?;
@@ -35,8 +35,10 @@ impl TailwindScrollPadding {
             ["pr", rest @ ..] => (SpacingAxis::new("scroll-pr", &["scroll-padding-right"]), rest),
             ["pt", rest @ ..] => (SpacingAxis::new("scroll-pt", &["scroll-padding-top"]), rest),
             ["pb", rest @ ..] => (SpacingAxis::new("scroll-pb", &["scroll-padding-bottom"]), rest),
+            ["ps", rest @ ..] => (SpacingAxis::new("scroll-ps", &["scroll-padding-inline-start"]), rest),
+            ["pe", rest @ ..] => (SpacingAxis::new("scroll-pe", &["scroll-padding-inline-end"]), rest),
             ["px", rest @ ..] => (SpacingAxis::new("scroll-px", &["scroll-padding-left", "scroll-padding-right"]), rest),
-            ["py", rest @ ..] => (SpacingAxis::new("scroll-py", &["scroll-padding"]), rest),
+            ["py", rest @ ..] => (SpacingAxis::new("scroll-py", &["scroll-padding-top", "scroll-padding-bottom"]), rest),
             _ => return syntax_error!("Unknown scroll-padding axis"),
         };
         let size = SpacingSize::parse(rest, arbitrary, &Self::check_valid)?;
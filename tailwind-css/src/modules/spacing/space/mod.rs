@@ -19,13 +19,35 @@ impl Display for TailwindSpace {
 }
 
 impl TailwindInstance for TailwindSpace {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
+    }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        let class = match self.axis {
-            true => "margin-left",
-            false => "margin-top",
-        };
-        css_attributes! {
-            class => self.size.get_properties()
+        let size = self.negative.get_properties(&self.size.get_properties());
+        // keywords such as `auto` can not be scaled by the reverse variable
+        if let SpacingSize::Standard(_) = self.size {
+            let class = match self.axis {
+                true => "margin-left",
+                false => "margin-top",
+            };
+            return css_attributes! {
+                class => size
+            };
+        }
+        match self.axis {
+            true => css_attributes! {
+                "--tw-space-x-reverse" => "0",
+                "margin-right" => format!("calc({} * var(--tw-space-x-reverse))", size),
+                "margin-left" => format!("calc({} * calc(1 - var(--tw-space-x-reverse)))", size)
+            },
+            false => css_attributes! {
+                "--tw-space-y-reverse" => "0",
+                "margin-top" => format!("calc({} * calc(1 - var(--tw-space-y-reverse)))", size),
+                "margin-bottom" => format!("calc({} * var(--tw-space-y-reverse))", size)
+            },
         }
     }
 }
@@ -50,6 +72,10 @@ impl TailwindSpace {
             ["reverse"] => Ok(TailwindSpaceReverse::from(axis).boxed()),
             _ => {
                 let size = SpacingSize::parse(pattern, arbitrary, &Self::check_valid)?;
+                // `-space-x-auto` would emit `-auto`
+                if let (SpacingSize::Standard(s), true) = (&size, negative.0) {
+                    return syntax_error!("Keyword space can not be negative: {}", s);
+                }
                 Ok(Self { axis, negative, size }.boxed())
             },
         }
@@ -22,6 +22,12 @@ impl Display for TailwindSpaceReverse {
 }
 
 impl TailwindInstance for TailwindSpaceReverse {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        format!(".{}>:not([hidden])~:not([hidden])", self.id())
+    }
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let class = match self.axis {
             true => "--tw-space-x-reverse",
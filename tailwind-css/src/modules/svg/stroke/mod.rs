@@ -24,6 +24,10 @@ impl TailwindStroke {
             [theme, weight] => color(TailwindColor::parse_themed(theme, weight)?),
             // https://tailwindcss.com/docs/text-decoration-thickness
             [n] => maybe_width(n)?,
+            [] if matches!(arbitrary.get_hint(), Some("length" | "number" | "percentage")) => {
+                TailwindStrokeWidth::parse_arbitrary(arbitrary)?.boxed()
+            },
+            [] => color(TailwindColor::parse_arbitrary(arbitrary)?),
             _ => return syntax_error!("Unknown decoration instructions: {}", str.join("-")),
         };
         Ok(out)
@@ -9,6 +9,7 @@ pub struct TailwindStrokeWidth {
 enum StrokeWidth {
     Unit(i32),
     Length(LengthUnit),
+    Arbitrary(TailwindArbitrary),
 }
 
 impl Display for StrokeWidth {
@@ -16,6 +17,7 @@ impl Display for StrokeWidth {
         match self {
             StrokeWidth::Unit(s) => write!(f, "{}", s),
             StrokeWidth::Length(s) => write!(f, "{}", s.get_class()),
+            StrokeWidth::Arbitrary(s) => s.write(f),
         }
     }
 }
@@ -28,9 +30,10 @@ impl Display for TailwindStrokeWidth {
 
 impl TailwindInstance for TailwindStrokeWidth {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
-        let width = match self.kind {
+        let width = match &self.kind {
             StrokeWidth::Unit(s) => format!("{}px", s),
             StrokeWidth::Length(s) => s.get_properties(),
+            StrokeWidth::Arbitrary(s) => s.get_properties(),
         };
         css_attributes! {
             "stroke-width" => width
@@ -43,6 +46,10 @@ impl TailwindStrokeWidth {
     pub fn try_new(width: &str) -> Result<Self> {
         Ok(Self { kind: StrokeWidth::parse(width)? })
     }
+    /// `stroke-[length:var(--width)]`
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: StrokeWidth::Arbitrary(TailwindArbitrary::new(arbitrary)?) })
+    }
 }
 
 impl StrokeWidth {
@@ -1,14 +1,17 @@
 pub use self::{
-    origin::TailwindOrigin, rotate::TailwindRotate, scale::TailwindScale, skew::TailwindSkew, translate::TailwindTranslate,
+    origin::TailwindOrigin, rotate::TailwindRotate, scale::TailwindScale, skew::TailwindSkew, transform::TailwindTransform,
+    translate::TailwindTranslate,
 };
 use crate::{
     css_attributes, AnchorPoint, AxisXY, CssAttributes, Negative, NumericValue, Result, TailwindArbitrary, TailwindBuilder,
     TailwindInstance, UnitValue,
 };
+use crate::syntax_error;
 use std::fmt::{Debug, Display, Formatter};
 
 mod origin;
 mod rotate;
 mod scale;
 mod skew;
+mod transform;
 mod translate;
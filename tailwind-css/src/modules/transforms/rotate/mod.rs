@@ -16,9 +16,9 @@ impl Display for TailwindRotate {
 impl TailwindInstance for TailwindRotate {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let deg = self.kind.get_properties(|f| format!("{}deg", f));
-        let transform = format!("rotate({})", deg);
         css_attributes! {
-            "transform" => transform,
+            "--tw-rotate" => deg,
+            "transform" => TailwindTransform::CSS,
         }
     }
 }
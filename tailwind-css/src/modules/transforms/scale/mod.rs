@@ -20,13 +20,20 @@ impl Display for TailwindScale {
 impl TailwindInstance for TailwindScale {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let scale = self.kind.get_properties(|f| (f / 100.0).to_string());
-        let transform = match self.axis {
-            AxisXY::N => format!("scale({})", scale),
-            AxisXY::X => format!("scaleX({})", scale),
-            AxisXY::Y => format!("scaleY({})", scale),
-        };
-        css_attributes! {
-            "transform" => transform,
+        match self.axis {
+            AxisXY::N => css_attributes! {
+                "--tw-scale-x" => &scale,
+                "--tw-scale-y" => scale,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::X => css_attributes! {
+                "--tw-scale-x" => scale,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::Y => css_attributes! {
+                "--tw-scale-y" => scale,
+                "transform" => TailwindTransform::CSS,
+            },
         }
     }
 }
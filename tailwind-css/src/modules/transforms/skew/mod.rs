@@ -17,13 +17,20 @@ impl Display for TailwindSkew {
 impl TailwindInstance for TailwindSkew {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let deg = self.kind.get_properties(|n| format!("{}deg", n));
-        let transform = match self.axis {
-            AxisXY::X => format!("skewX({})", deg),
-            AxisXY::Y => format!("skewY({})", deg),
-            AxisXY::N => format!("skew({})", deg),
-        };
-        css_attributes! {
-            "transform" => transform,
+        match self.axis {
+            AxisXY::X => css_attributes! {
+                "--tw-skew-x" => deg,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::Y => css_attributes! {
+                "--tw-skew-y" => deg,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::N => css_attributes! {
+                "--tw-skew-x" => &deg,
+                "--tw-skew-y" => deg,
+                "transform" => TailwindTransform::CSS,
+            },
         }
     }
 }
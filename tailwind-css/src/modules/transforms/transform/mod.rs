@@ -0,0 +1,75 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+enum Transform {
+    Cpu,
+    Gpu,
+    None,
+    Arbitrary(TailwindArbitrary),
+}
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindTransform {
+    kind: Transform,
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cpu => write!(f, "cpu"),
+            Self::Gpu => write!(f, "gpu"),
+            Self::None => write!(f, "none"),
+            Self::Arbitrary(g) => write!(f, "[{}]", g.get_class()),
+        }
+    }
+}
+
+impl Display for TailwindTransform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "transform-{}", self.kind)
+    }
+}
+
+impl TailwindInstance for TailwindTransform {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        match &self.kind {
+            Transform::Cpu => css_attributes! {
+                "--tw-transform-gpu" => "initial",
+                "transform" => Self::CSS
+            },
+            Transform::Gpu => css_attributes! {
+                "--tw-transform-gpu" => "translateZ(0)",
+                "transform" => Self::CSS
+            },
+            Transform::None => css_attributes! {
+                "transform" => "none"
+            },
+            Transform::Arbitrary(g) => css_attributes! {
+                "transform" => g.get_properties()
+            },
+        }
+    }
+}
+
+impl TailwindTransform {
+    /// The canonical transform every transform utility writes, only the `--tw-*` variables differ
+    ///
+    /// `transform-gpu` sets `--tw-transform-gpu` to `translateZ(0)` to promote the element to its own layer,
+    /// so it keeps working whatever order the other transform utilities come in.
+    pub(crate) const CSS: &'static str = "translate(var(--tw-translate-x, 0), var(--tw-translate-y, 0)) var(--tw-transform-gpu, ) \
+                                          rotate(var(--tw-rotate, 0)) skewX(var(--tw-skew-x, 0)) skewY(var(--tw-skew-y, 0)) \
+                                          scaleX(var(--tw-scale-x, 1)) scaleY(var(--tw-scale-y, 1))";
+    /// <https://v3.tailwindcss.com/docs/transform>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            [] if arbitrary.is_none() => Transform::Cpu,
+            [] => Transform::Arbitrary(TailwindArbitrary::new(arbitrary)?),
+            ["cpu"] => Transform::Cpu,
+            ["gpu"] => Transform::Gpu,
+            ["none"] => Transform::None,
+            _ => return syntax_error!("Unknown transform instructions: {}", pattern.join("-")),
+        };
+        Ok(Self { kind })
+    }
+}
@@ -21,13 +21,20 @@ impl Display for TailwindTranslate {
 impl TailwindInstance for TailwindTranslate {
     fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
         let size = self.kind.get_properties(|n| format!("{}rem", n / 4.0));
-        let transform = match self.axis {
-            AxisXY::X => format!("translateX({})", size),
-            AxisXY::Y => format!("translateY({})", size),
-            AxisXY::N => format!("translate({})", size),
-        };
-        css_attributes! {
-            "transform" => transform,
+        match self.axis {
+            AxisXY::X => css_attributes! {
+                "--tw-translate-x" => size,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::Y => css_attributes! {
+                "--tw-translate-y" => size,
+                "transform" => TailwindTransform::CSS,
+            },
+            AxisXY::N => css_attributes! {
+                "--tw-translate-x" => &size,
+                "--tw-translate-y" => size,
+                "transform" => TailwindTransform::CSS,
+            },
         }
     }
 }
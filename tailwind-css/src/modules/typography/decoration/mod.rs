@@ -28,6 +28,10 @@ impl TailwindDecoration {
                 TailwindDecorationColor::from(color).boxed()
             },
             // https://tailwindcss.com/docs/text-decoration-thickness
+            [] if arbitrary.get_hint() == Some("color") => TailwindDecorationColor::parse_arbitrary(arbitrary)?.boxed(),
+            [] if matches!(arbitrary.get_hint(), Some("length" | "percentage")) => {
+                TailwindDecorationThickness::parse(pattern, arbitrary)?.boxed()
+            },
             [] => TailwindDecoration { arbitrary: TailwindArbitrary::new(arbitrary)? }.boxed(),
             [n] => resolve1(n)?,
             _ => TailwindDecorationColor::parse(pattern, arbitrary)?.boxed(),
@@ -27,6 +27,7 @@ impl Display for TailwindDecorationThickness {
                 "from-font" | "auto" => write!(f, "{}", s),
                 _ => write!(f, "thick-{}", s),
             },
+            NumericValue::Arbitrary(s) if s.get_hint().is_some() => s.write(f),
             NumericValue::Arbitrary(s) => s.write_class(f, "thick-"),
         }
     }
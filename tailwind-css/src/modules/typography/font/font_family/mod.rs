@@ -14,3 +14,10 @@ impl Display for TailwindFontFamily {
         write!(f, "font-{}", self.kind)
     }
 }
+
+impl TailwindFontFamily {
+    /// `font-[family-name:var(--font)]`
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: StandardValue::parse_arbitrary(arbitrary)? })
+    }
+}
@@ -1,26 +1,36 @@
 use super::*;
+use crate::StandardValue;
 
 #[doc=include_str!("readme.md")]
 #[derive(Debug, Clone)]
 pub struct TailwindFontSize {
-    name: String,
+    kind: StandardValue,
 }
 
 impl Display for TailwindFontSize {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "text-{}", self.name)
+        write!(f, "text-{}", self.kind)
     }
 }
 
 impl TailwindInstance for TailwindFontSize {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
-        ctx.fonts.get_size(&self.name).get_properties()
+        match &self.kind {
+            StandardValue::Keyword(s) => ctx.fonts.get_size(s).get_properties(),
+            StandardValue::Arbitrary(s) => css_attributes! {
+                "font-size" => s.get_properties()
+            },
+        }
     }
 }
 
 impl TailwindFontSize {
     #[inline]
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string() }
+        Self { kind: StandardValue::from(name) }
+    }
+    /// `text-[length:var(--size)]`
+    pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<Self> {
+        Ok(Self { kind: StandardValue::parse_arbitrary(arbitrary)? })
     }
 }
@@ -23,6 +23,8 @@ pub fn font_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<B
         ["extrabold"] => TailwindFontWeight::EXTRA_BOLD.boxed(),
         ["black"] => TailwindFontWeight::BLACK.boxed(),
         ["size"] => maybe_size(arbitrary)?,
+        // https://tailwindcss.com/docs/font-family#using-custom-values
+        [] if arbitrary.get_hint() == Some("family-name") => TailwindFontFamily::parse_arbitrary(arbitrary)?.boxed(),
         ["size", n] => {
             let a = TailwindArbitrary::from(*n);
             maybe_size(&a)?
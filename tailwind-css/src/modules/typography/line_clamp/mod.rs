@@ -0,0 +1,62 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+#[derive(Debug, Clone)]
+pub struct TailwindLineClamp {
+    kind: LineClamp,
+}
+
+#[derive(Debug, Clone)]
+enum LineClamp {
+    Lines(u32),
+    None,
+    Arbitrary(TailwindArbitrary),
+}
+
+impl Display for TailwindLineClamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            LineClamp::Lines(n) => write!(f, "line-clamp-{}", n),
+            LineClamp::None => write!(f, "line-clamp-none"),
+            LineClamp::Arbitrary(s) => write!(f, "line-clamp-{}", s.get_class()),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindLineClamp {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let lines = match &self.kind {
+            LineClamp::Lines(n) => n.to_string(),
+            LineClamp::Arbitrary(s) => s.get_properties(),
+            LineClamp::None =>
+                return css_attributes! {
+                    "overflow" => "visible",
+                    "display" => "block",
+                    "-webkit-box-orient" => "horizontal",
+                    "-webkit-line-clamp" => "none",
+                },
+        };
+        css_attributes! {
+            "overflow" => "hidden",
+            "display" => "-webkit-box",
+            "-webkit-box-orient" => "vertical",
+            "-webkit-line-clamp" => lines,
+        }
+    }
+}
+
+impl TailwindLineClamp {
+    /// <https://tailwindcss.com/docs/line-clamp>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            ["none"] => LineClamp::None,
+            [] if !arbitrary.is_none() => LineClamp::Arbitrary(TailwindArbitrary::new(arbitrary)?),
+            [n] => match n.parse::<u32>() {
+                Ok(n) if n > 0 => LineClamp::Lines(n),
+                _ => return syntax_error!("Unknown line-clamp instructions: {}", pattern.join("-")),
+            },
+            _ => return syntax_error!("Unknown line-clamp instructions: {}", pattern.join("-")),
+        };
+        Ok(Self { kind })
+    }
+}
@@ -12,7 +12,9 @@ pub use self::{
     },
     indent::TailwindIndent,
     leading::TailwindLeading,
+    line_clamp::TailwindLineClamp,
     list::{list_position::TailwindListPosition, list_type::TailwindListStyle},
+    prose::{element::TailwindProseElement, TailwindProse},
     text::{
         text_adaptor, text_align::TailwindTextAlignment, text_color::TailwindTextColor, text_overflow::TailwindTextOverflow,
         text_transform::TailwindTextTransform,
@@ -23,11 +25,12 @@ pub use self::{
 };
 use crate::{
     css_attributes, syntax_error, CssAttributes, LengthUnit, Result, StandardValue, TailwindArbitrary, TailwindBreakAfter,
-    TailwindBreakBefore, TailwindBreakInside, TailwindBuilder, TailwindColor, TailwindInstance,
+    TailwindBreakBefore, TailwindBreakInside, TailwindBuilder, TailwindColor, TailwindInstance, TailwindShadowColor,
+    TailwindTextShadow,
 };
 use std::{
     collections::BTreeSet,
-    fmt::{Display, Formatter},
+    fmt::{Debug, Display, Formatter},
 };
 
 mod align;
@@ -36,7 +39,9 @@ mod decoration;
 mod font;
 mod indent;
 mod leading;
+mod line_clamp;
 mod list;
+mod prose;
 mod text;
 mod tracking;
 mod underline_offset;
@@ -0,0 +1,222 @@
+use super::*;
+
+impl TailwindProse {
+    /// Skip everything inside a `not-prose` element
+    pub(crate) const NOT_PROSE: &'static str = ":not(:where([class~=\"not-prose\"],[class~=\"not-prose\"] *))";
+    /// Color variables shared by the gray themes and `prose-invert`
+    pub(crate) const VARIABLES: [&'static str; 16] = [
+        "body",
+        "headings",
+        "lead",
+        "links",
+        "bold",
+        "counters",
+        "bullets",
+        "hr",
+        "quotes",
+        "quote-borders",
+        "captions",
+        "code",
+        "pre-code",
+        "pre-bg",
+        "th-borders",
+        "td-borders",
+    ];
+    /// `(variable, light weight, inverted weight)` of a gray theme
+    pub(crate) const GRAY_WEIGHTS: [(&'static str, &'static str, &'static str); 16] = [
+        ("body", "700", "300"),
+        ("headings", "900", "white"),
+        ("lead", "600", "400"),
+        ("links", "900", "white"),
+        ("bold", "900", "white"),
+        ("counters", "500", "400"),
+        ("bullets", "300", "600"),
+        ("hr", "200", "700"),
+        ("quotes", "900", "100"),
+        ("quote-borders", "200", "700"),
+        ("captions", "500", "400"),
+        ("code", "900", "white"),
+        ("pre-code", "200", "300"),
+        ("pre-bg", "800", "black/50"),
+        ("th-borders", "300", "600"),
+        ("td-borders", "200", "700"),
+    ];
+    /// Size independent element styles of `prose`
+    pub(crate) const ELEMENTS: &'static [(&'static str, &'static str)] = &[
+        ("[class~=\"lead\"]", "color:var(--tw-prose-lead)"),
+        ("a", "color:var(--tw-prose-links);text-decoration:underline;font-weight:500"),
+        ("strong", "color:var(--tw-prose-bold);font-weight:600"),
+        ("a strong,blockquote strong,thead th strong", "color:inherit"),
+        ("ol", "list-style-type:decimal"),
+        ("ul", "list-style-type:disc"),
+        ("ol>li::marker", "font-weight:400;color:var(--tw-prose-counters)"),
+        ("ul>li::marker", "color:var(--tw-prose-bullets)"),
+        ("hr", "border-color:var(--tw-prose-hr);border-top-width:1px"),
+        (
+            "blockquote",
+            "font-weight:500;font-style:italic;color:var(--tw-prose-quotes);border-left-width:.25rem;\
+             border-left-color:var(--tw-prose-quote-borders);quotes:\"\\201C\"\"\\201D\"\"\\2018\"\"\\2019\"",
+        ),
+        ("h1", "color:var(--tw-prose-headings);font-weight:800"),
+        ("h2", "color:var(--tw-prose-headings);font-weight:700"),
+        ("h3", "color:var(--tw-prose-headings);font-weight:600"),
+        ("h4", "color:var(--tw-prose-headings);font-weight:600"),
+        ("figcaption", "color:var(--tw-prose-captions)"),
+        ("code", "color:var(--tw-prose-code);font-weight:600"),
+        ("code::before", "content:\"`\""),
+        ("code::after", "content:\"`\""),
+        ("pre", "color:var(--tw-prose-pre-code);background-color:var(--tw-prose-pre-bg);overflow-x:auto;font-weight:400"),
+        (
+            "pre code",
+            "background-color:transparent;border-width:0;border-radius:0;padding:0;font-weight:inherit;color:inherit;\
+             font-size:inherit;font-family:inherit;line-height:inherit",
+        ),
+        ("pre code::before", "content:none"),
+        ("pre code::after", "content:none"),
+        ("table", "width:100%;table-layout:auto;text-align:left"),
+        ("thead", "border-bottom-width:1px;border-bottom-color:var(--tw-prose-th-borders)"),
+        ("thead th", "color:var(--tw-prose-headings);font-weight:600;vertical-align:bottom"),
+        ("tbody tr", "border-bottom-width:1px;border-bottom-color:var(--tw-prose-td-borders)"),
+        ("tbody tr:last-child", "border-bottom-width:0"),
+        ("tbody td", "vertical-align:baseline"),
+    ];
+    /// `prose` itself uses the `base` scale
+    pub(crate) const BASE: &'static ProseScale = &Self::SCALES[1];
+    pub(crate) const SCALES: [ProseScale; 5] = [
+        ProseScale {
+            name: "sm",
+            font_size: ".875rem",
+            line_height: "1.7142857",
+            rules: &[
+                ("p", "margin-top:1.1428571em;margin-bottom:1.1428571em"),
+                ("[class~=\"lead\"]", "font-size:1.2857143em;line-height:1.5555556;margin-top:.8888889em;margin-bottom:.8888889em"),
+                ("blockquote", "margin-top:1.3333333em;margin-bottom:1.3333333em;padding-left:1.1111111em"),
+                ("h1", "font-size:2.1428571em;margin-top:0;margin-bottom:.8em;line-height:1.2"),
+                ("h2", "font-size:1.4285714em;margin-top:1.6em;margin-bottom:.8em;line-height:1.4"),
+                ("h3", "font-size:1.2857143em;margin-top:1.5555556em;margin-bottom:.4444444em;line-height:1.5555556"),
+                ("h4", "margin-top:1.4285714em;margin-bottom:.5714286em;line-height:1.4285714"),
+                ("img,video,figure", "margin-top:1.7142857em;margin-bottom:1.7142857em"),
+                ("code", "font-size:.8571429em"),
+                (
+                    "pre",
+                    "font-size:.8571429em;line-height:1.6666667;margin-top:1.6666667em;margin-bottom:1.6666667em;\
+                     border-radius:.25rem;padding:.6666667em 1em",
+                ),
+                ("ol,ul", "margin-top:1.1428571em;margin-bottom:1.1428571em;padding-left:1.5714286em"),
+                ("li", "margin-top:.2857143em;margin-bottom:.2857143em"),
+                ("hr", "margin-top:2.8571429em;margin-bottom:2.8571429em"),
+                ("table", "font-size:.8571429em;line-height:1.5"),
+                ("thead th", "padding:0 1em .6666667em"),
+                ("tbody td", "padding:.6666667em 1em"),
+            ],
+        },
+        ProseScale {
+            name: "base",
+            font_size: "1rem",
+            line_height: "1.75",
+            rules: &[
+                ("p", "margin-top:1.25em;margin-bottom:1.25em"),
+                ("[class~=\"lead\"]", "font-size:1.25em;line-height:1.6;margin-top:1.2em;margin-bottom:1.2em"),
+                ("blockquote", "margin-top:1.6em;margin-bottom:1.6em;padding-left:1em"),
+                ("h1", "font-size:2.25em;margin-top:0;margin-bottom:.8888889em;line-height:1.1111111"),
+                ("h2", "font-size:1.5em;margin-top:2em;margin-bottom:1em;line-height:1.3333333"),
+                ("h3", "font-size:1.25em;margin-top:1.6em;margin-bottom:.6em;line-height:1.6"),
+                ("h4", "margin-top:1.5em;margin-bottom:.5em;line-height:1.5"),
+                ("img,video,figure", "margin-top:2em;margin-bottom:2em"),
+                ("code", "font-size:.875em"),
+                (
+                    "pre",
+                    "font-size:.875em;line-height:1.7142857;margin-top:1.7142857em;margin-bottom:1.7142857em;\
+                     border-radius:.375rem;padding:.8571429em 1.1428571em",
+                ),
+                ("ol,ul", "margin-top:1.25em;margin-bottom:1.25em;padding-left:1.625em"),
+                ("li", "margin-top:.5em;margin-bottom:.5em"),
+                ("hr", "margin-top:3em;margin-bottom:3em"),
+                ("table", "font-size:.875em;line-height:1.7142857"),
+                ("thead th", "padding:0 .5714286em .5714286em"),
+                ("tbody td", "padding:.5714286em"),
+            ],
+        },
+        ProseScale {
+            name: "lg",
+            font_size: "1.125rem",
+            line_height: "1.7777778",
+            rules: &[
+                ("p", "margin-top:1.3333333em;margin-bottom:1.3333333em"),
+                ("[class~=\"lead\"]", "font-size:1.2222222em;line-height:1.4545455;margin-top:1.0909091em;margin-bottom:1.0909091em"),
+                ("blockquote", "margin-top:1.6666667em;margin-bottom:1.6666667em;padding-left:1em"),
+                ("h1", "font-size:2.6666667em;margin-top:0;margin-bottom:.8333333em;line-height:1"),
+                ("h2", "font-size:1.6666667em;margin-top:1.8666667em;margin-bottom:1.0666667em;line-height:1.3333333"),
+                ("h3", "font-size:1.3333333em;margin-top:1.6666667em;margin-bottom:.6666667em;line-height:1.5"),
+                ("h4", "margin-top:1.7777778em;margin-bottom:.4444444em;line-height:1.5555556"),
+                ("img,video,figure", "margin-top:1.7777778em;margin-bottom:1.7777778em"),
+                ("code", "font-size:.8888889em"),
+                (
+                    "pre",
+                    "font-size:.8888889em;line-height:1.75;margin-top:2em;margin-bottom:2em;border-radius:.375rem;\
+                     padding:1em 1.5em",
+                ),
+                ("ol,ul", "margin-top:1.3333333em;margin-bottom:1.3333333em;padding-left:1.5555556em"),
+                ("li", "margin-top:.6666667em;margin-bottom:.6666667em"),
+                ("hr", "margin-top:3.1111111em;margin-bottom:3.1111111em"),
+                ("table", "font-size:.8888889em;line-height:1.5"),
+                ("thead th", "padding:0 .75em .75em"),
+                ("tbody td", "padding:.75em"),
+            ],
+        },
+        ProseScale {
+            name: "xl",
+            font_size: "1.25rem",
+            line_height: "1.8",
+            rules: &[
+                ("p", "margin-top:1.2em;margin-bottom:1.2em"),
+                ("[class~=\"lead\"]", "font-size:1.2em;line-height:1.5;margin-top:1em;margin-bottom:1em"),
+                ("blockquote", "margin-top:1.6em;margin-bottom:1.6em;padding-left:1.0666667em"),
+                ("h1", "font-size:2.8em;margin-top:0;margin-bottom:.8571429em;line-height:1"),
+                ("h2", "font-size:1.8em;margin-top:1.5555556em;margin-bottom:.8888889em;line-height:1.1111111"),
+                ("h3", "font-size:1.5em;margin-top:1.6em;margin-bottom:.6666667em;line-height:1.3333333"),
+                ("h4", "margin-top:1.8em;margin-bottom:.6em;line-height:1.6"),
+                ("img,video,figure", "margin-top:2em;margin-bottom:2em"),
+                ("code", "font-size:.9em"),
+                (
+                    "pre",
+                    "font-size:.9em;line-height:1.7777778;margin-top:2em;margin-bottom:2em;border-radius:.5rem;\
+                     padding:1.1111111em 1.3333333em",
+                ),
+                ("ol,ul", "margin-top:1.2em;margin-bottom:1.2em;padding-left:1.6em"),
+                ("li", "margin-top:.6em;margin-bottom:.6em"),
+                ("hr", "margin-top:2.8em;margin-bottom:2.8em"),
+                ("table", "font-size:.9em;line-height:1.5555556"),
+                ("thead th", "padding:0 .6666667em .8888889em"),
+                ("tbody td", "padding:.8888889em .6666667em"),
+            ],
+        },
+        ProseScale {
+            name: "2xl",
+            font_size: "1.5rem",
+            line_height: "1.6666667",
+            rules: &[
+                ("p", "margin-top:1.3333333em;margin-bottom:1.3333333em"),
+                ("[class~=\"lead\"]", "font-size:1.25em;line-height:1.4666667;margin-top:1.0666667em;margin-bottom:1.0666667em"),
+                ("blockquote", "margin-top:1.7777778em;margin-bottom:1.7777778em;padding-left:1.1111111em"),
+                ("h1", "font-size:2.6666667em;margin-top:0;margin-bottom:.875em;line-height:1"),
+                ("h2", "font-size:2em;margin-top:1.5em;margin-bottom:.8333333em;line-height:1.0833333"),
+                ("h3", "font-size:1.5em;margin-top:1.5555556em;margin-bottom:.6666667em;line-height:1.2222222"),
+                ("h4", "margin-top:1.6666667em;margin-bottom:.6666667em;line-height:1.5"),
+                ("img,video,figure", "margin-top:2em;margin-bottom:2em"),
+                ("code", "font-size:.8333333em"),
+                (
+                    "pre",
+                    "font-size:.8333333em;line-height:1.8;margin-top:2em;margin-bottom:2em;border-radius:.75rem;\
+                     padding:1.2em 1.6em",
+                ),
+                ("ol,ul", "margin-top:1.3333333em;margin-bottom:1.3333333em;padding-left:1.5833333em"),
+                ("li", "margin-top:.5em;margin-bottom:.5em"),
+                ("hr", "margin-top:3em;margin-bottom:3em"),
+                ("table", "font-size:.8333333em;line-height:1.4"),
+                ("thead th", "padding:0 .6em .8em"),
+                ("tbody td", "padding:.8em .6em"),
+            ],
+        },
+    ];
+}
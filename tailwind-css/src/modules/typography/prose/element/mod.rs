@@ -0,0 +1,70 @@
+use super::*;
+
+#[doc=include_str!("readme.md")]
+pub struct TailwindProseElement {
+    name: String,
+    selector: &'static str,
+    inner: Box<dyn TailwindInstance>,
+}
+
+impl Debug for TailwindProseElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TailwindProseElement").field("name", &self.name).field("inner", &self.inner.id()).finish()
+    }
+}
+
+impl Display for TailwindProseElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "prose-{}:{}", self.name, self.inner)
+    }
+}
+
+impl TailwindInstance for TailwindProseElement {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        // sorted after `.prose`, so the modifier wins over the defaults at the same specificity
+        TailwindProse::descendant(&self.id(), self.selector)
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        self.inner.attributes(ctx)
+    }
+}
+
+impl TailwindProseElement {
+    /// <https://github.com/tailwindlabs/tailwindcss-typography#element-modifiers>
+    pub fn parse(element: &[String], inner: Box<dyn TailwindInstance>) -> Result<Self> {
+        let name = element.join("-");
+        let selector = match name.as_str() {
+            "headings" => "h1,h2,h3,h4,th",
+            "lead" => "[class~=\"lead\"]",
+            "h1" => "h1",
+            "h2" => "h2",
+            "h3" => "h3",
+            "h4" => "h4",
+            "p" => "p",
+            "a" => "a",
+            "blockquote" => "blockquote",
+            "figure" => "figure",
+            "figcaption" => "figcaption",
+            "strong" => "strong",
+            "em" => "em",
+            "code" => "code",
+            "pre" => "pre",
+            "ol" => "ol",
+            "ul" => "ul",
+            "li" => "li",
+            "table" => "table",
+            "thead" => "thead",
+            "tr" => "tr",
+            "th" => "th",
+            "td" => "td",
+            "img" => "img",
+            "video" => "video",
+            "hr" => "hr",
+            _ => return syntax_error!("Unknown prose element: prose-{}", name),
+        };
+        Ok(Self { name, selector, inner })
+    }
+}
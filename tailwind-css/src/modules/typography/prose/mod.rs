@@ -0,0 +1,142 @@
+use super::*;
+
+mod builtin;
+pub(crate) mod element;
+
+#[derive(Clone, Debug)]
+enum Prose {
+    Base,
+    Size(&'static ProseScale),
+    Gray(String),
+    Invert,
+}
+
+/// Font size, line height and the descendant rules of one `prose-{size}`
+#[derive(Debug)]
+pub(crate) struct ProseScale {
+    name: &'static str,
+    font_size: &'static str,
+    line_height: &'static str,
+    rules: &'static [(&'static str, &'static str)],
+}
+
+#[doc=include_str!("readme.md")]
+#[derive(Clone, Debug)]
+pub struct TailwindProse {
+    kind: Prose,
+}
+
+impl Display for TailwindProse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            Prose::Base => write!(f, "prose"),
+            Prose::Size(s) => write!(f, "prose-{}", s.name),
+            Prose::Gray(s) => write!(f, "prose-{}", s),
+            Prose::Invert => write!(f, "prose-invert"),
+        }
+    }
+}
+
+impl TailwindInstance for TailwindProse {
+    fn inlineable(&self) -> bool {
+        false
+    }
+    fn selectors(&self, _: &TailwindBuilder) -> String {
+        match &self.kind {
+            // rules are sorted by class, the extra `[class]` lets `prose-invert` beat `prose-slate`
+            Prose::Invert => format!(".{}[class]", self.id()),
+            _ => format!(".{}", self.id()),
+        }
+    }
+    fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
+        match &self.kind {
+            Prose::Base => {
+                let mut css = css_attributes! {
+                    "color" => "var(--tw-prose-body)",
+                    "max-width" => "65ch",
+                    "font-size" => Self::BASE.font_size,
+                    "line-height" => Self::BASE.line_height,
+                };
+                css.extend(Self::gray_variables("gray", ctx));
+                css
+            },
+            Prose::Size(s) => css_attributes! {
+                "font-size" => s.font_size,
+                "line-height" => s.line_height,
+            },
+            Prose::Gray(s) => {
+                let mut css = CssAttributes::default();
+                css.extend(Self::gray_variables(s, ctx));
+                css
+            },
+            Prose::Invert => {
+                let mut css = CssAttributes::default();
+                for name in Self::VARIABLES {
+                    css.insert(format!("--tw-prose-{}", name), format!("var(--tw-prose-invert-{})", name));
+                }
+                css
+            },
+        }
+    }
+    fn additional(&self, ctx: &TailwindBuilder) -> String {
+        let class = ctx.prefixed(&self.id());
+        let mut out = String::new();
+        let mut write = |rules: &[(&str, &str)]| {
+            for (selector, declarations) in rules {
+                out.push_str(&Self::descendant(&class, selector));
+                out.push('{');
+                out.push_str(declarations);
+                out.push('}');
+            }
+        };
+        match &self.kind {
+            Prose::Base => {
+                write(Self::ELEMENTS);
+                write(Self::BASE.rules);
+            },
+            Prose::Size(s) => write(s.rules),
+            Prose::Gray(_) | Prose::Invert => {},
+        }
+        out
+    }
+}
+
+impl TailwindProse {
+    /// <https://github.com/tailwindlabs/tailwindcss-typography>
+    pub fn parse(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Self> {
+        let kind = match pattern {
+            [] if arbitrary.is_none() => Prose::Base,
+            ["invert"] => Prose::Invert,
+            [s @ ("slate" | "gray" | "zinc" | "neutral" | "stone")] => Prose::Gray(s.to_string()),
+            [s] => match Self::SCALES.iter().find(|scale| scale.name == *s) {
+                Some(scale) => Prose::Size(scale),
+                None => return syntax_error!("Unknown prose instructions: {}", pattern.join("-")),
+            },
+            _ => return syntax_error!("Unknown prose instructions: {}", pattern.join("-")),
+        };
+        Ok(Self { kind })
+    }
+    /// `.prose :where(p):not(:where([class~="not-prose"],[class~="not-prose"] *))`
+    ///
+    /// A trailing pseudo element like `code::before` is moved out of the `:where`
+    pub(crate) fn descendant(class: &str, selector: &str) -> String {
+        let (selector, pseudo) = match selector.split_once("::") {
+            Some((s, p)) => (s, format!("::{}", p)),
+            None => (selector, String::new()),
+        };
+        format!(".{} :where({}){}{}", class, selector, Self::NOT_PROSE, pseudo)
+    }
+    fn gray_variables(gray: &str, ctx: &TailwindBuilder) -> Vec<(String, String)> {
+        let color = |weight: &str| match weight {
+            "white" => TailwindColor::White.get_properties(ctx),
+            "black/50" => "rgb(0 0 0 / 50%)".to_string(),
+            w => TailwindColor::Themed(gray.to_string(), w.parse().unwrap_or(500)).get_properties(ctx),
+        };
+        let mut out = vec![];
+        for (name, light, dark) in Self::GRAY_WEIGHTS {
+            out.push((format!("--tw-prose-{}", name), color(light)));
+            out.push((format!("--tw-prose-invert-{}", name), color(dark)));
+        }
+        out
+    }
+}
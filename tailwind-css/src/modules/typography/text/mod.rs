@@ -5,7 +5,7 @@ pub(crate) mod text_color;
 pub(crate) mod text_overflow;
 pub(crate) mod text_transform;
 
-pub fn text_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Box<dyn TailwindInstance>> {
+pub fn text_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Box<dyn TailwindInstance>> {
     let out = match pattern {
         // https://tailwindcss.com/docs/text-align
         [s @ ("left" | "center" | "right" | "justify" | "start" | "end")] => TailwindTextAlignment::from(*s).boxed(),
@@ -18,6 +18,13 @@ pub fn text_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<B
         // https://tailwindcss.com/docs/font-size
         [s @ ("xs" | "sm" | "md" | "lg" | "xl" | "2xl" | "3xl" | "4xl" | "5xl" | "6xl" | "7xl" | "8xl" | "9xl")] =>
             TailwindFontSize::new(s).boxed(),
+        [] if matches!(arbitrary.get_hint(), Some("length" | "percentage")) => TailwindFontSize::parse_arbitrary(arbitrary)?.boxed(),
+        // https://tailwindcss.com/docs/text-shadow
+        ["shadow"] if arbitrary.is_none() => TailwindTextShadow::parse(&[], arbitrary, ctx)?.boxed(),
+        ["shadow"] if arbitrary.as_color().is_err() => TailwindTextShadow::parse_arbitrary(arbitrary)?.boxed(),
+        ["shadow", rest @ ..] if ctx.effects.has_text_shadow(&rest.join("-")) => TailwindTextShadow::parse(rest, arbitrary, ctx)?.boxed(),
+        ["shadow", "color", rest @ ..] => TailwindShadowColor::parse(rest, arbitrary, true)?.boxed(),
+        ["shadow", rest @ ..] => TailwindShadowColor::parse(rest, arbitrary, true)?.boxed(),
         // https://tailwindcss.com/docs/text-color
         _ => {
             let color = TailwindColor::parse(pattern, arbitrary)?;
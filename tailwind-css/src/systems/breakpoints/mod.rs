@@ -1,3 +1,4 @@
+use crate::{syntax_error, Result};
 use std::collections::BTreeMap;
 
 mod traits;
@@ -20,6 +21,14 @@ impl BreakPointSystem {
         new
     }
 
+    /// Min-width of the breakpoint in px
+    pub fn try_get_width(&self, name: &str) -> Result<usize> {
+        match self.inner.get(name) {
+            Some(s) => Ok(s.width),
+            None => syntax_error!("no such breakpoint: {}", name),
+        }
+    }
+
     #[inline]
     pub fn register(&mut self, name: String, width: usize) -> Option<BreakPoint> {
         self.inner.insert(name, BreakPoint { width })
@@ -0,0 +1,98 @@
+use super::*;
+
+/// What a class sets, used to find the classes it overrides
+struct MergeKey {
+    variants: Vec<String>,
+    combinator: String,
+    properties: BTreeSet<String>,
+    /// Properties that must all be overridden to drop the class, the `line-height` of `text-lg` is not one of them
+    owned: BTreeSet<String>,
+}
+
+impl MergeKey {
+    fn overrides(&self, other: &Self) -> bool {
+        self.variants == other.variants && self.combinator == other.combinator && other.owned.is_subset(&self.properties)
+    }
+}
+
+impl TailwindBuilder {
+    /// Merge class lists, a later class removes the earlier classes it overrides
+    ///
+    /// A class overrides another one with the same variants and selector if it sets all of its css properties,
+    /// so `px-2 p-4` => `p-4`, but `p-4 px-2` keeps both. Unknown classes are kept as written.
+    ///
+    /// Composed utilities such as `rotate-45 scale-110` or `shadow-md ring-2` are told apart by their `--tw-*` variables.
+    ///
+    /// ## Example
+    /// - input
+    /// ```html
+    /// p-4 text-red-500 hover:p-4, p-2 hover:p-2
+    /// ```
+    /// - output
+    /// ```html
+    /// text-red-500 p-2 hover:p-2
+    /// ```
+    pub fn merge_classes(&self, classes: &[&str]) -> String {
+        let input = classes.join(" ");
+        let (styles, diagnostics) = tailwind_ast::parse_tailwind_recover(&input);
+        let mut items: Vec<(usize, String, Option<MergeKey>)> =
+            diagnostics.into_iter().map(|d| (d.span.start, input[d.span].to_string(), None)).collect();
+        for style in styles {
+            let start = style.span.start;
+            let item = TailwindInstruction::from(style);
+            items.push((start, item.to_string(), self.get_merge_key(item.normalization())));
+        }
+        items.sort_by_key(|(start, ..)| *start);
+        let mut out: Vec<(String, Option<MergeKey>)> = Vec::with_capacity(items.len());
+        for (_, class, key) in items {
+            if let Some(key) = &key {
+                out.retain(|(_, old)| !matches!(old, Some(old) if key.overrides(old)));
+            }
+            out.push((class, key));
+        }
+        out.into_iter().map(|(class, _)| class).collect::<Vec<_>>().join(" ")
+    }
+    fn get_merge_key(&self, item: TailwindInstruction) -> Option<MergeKey> {
+        let instance = item.get_instance(self).ok()?;
+        let css = CssInstance::new(&*instance, self, false);
+        let mut properties = BTreeSet::new();
+        let composed = css.attribute.keys().any(|name| name.starts_with("--tw-"));
+        for name in css.attribute.keys() {
+            // `transform: translate(var(--tw-translate-x)) rotate(var(--tw-rotate))...` is shared by the whole group
+            if composed && matches!(name, "transform" | "filter" | "backdrop-filter" | "box-shadow") {
+                continue;
+            }
+            expand_property(name, &mut properties);
+        }
+        if properties.is_empty() {
+            return None;
+        }
+        let mut owned = properties.clone();
+        if owned.contains("font-size") {
+            owned.remove("line-height");
+        }
+        let variants = item.view_variants().iter().map(|v| v.to_string()).collect();
+        Some(MergeKey { variants, combinator: css.combinator, properties, owned })
+    }
+}
+
+/// Longhands of a shorthand property, so that `p-4` overrides `px-2`
+fn expand_property(name: &str, out: &mut BTreeSet<String>) {
+    const SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+    const CORNERS: [&str; 4] = ["top-left", "top-right", "bottom-right", "bottom-left"];
+    match name {
+        "inset" => out.extend(SIDES.iter().map(|side| side.to_string())),
+        "margin" | "padding" | "scroll-margin" | "scroll-padding" => {
+            out.extend(SIDES.iter().map(|side| format!("{}-{}", name, side)))
+        },
+        "border-width" | "border-style" | "border-color" => {
+            out.extend(SIDES.iter().map(|side| format!("border-{}-{}", side, &name["border-".len()..])))
+        },
+        "border-radius" => out.extend(CORNERS.iter().map(|corner| format!("border-{}-radius", corner))),
+        "gap" => out.extend(["row-gap", "column-gap"].iter().map(|s| s.to_string())),
+        "overflow" | "overscroll-behavior" => out.extend(["x", "y"].iter().map(|axis| format!("{}-{}", name, axis))),
+        _ => {
+            out.insert(name.to_string());
+        },
+    }
+}
@@ -6,5 +6,185 @@ impl TailwindBuilder {
     pub fn clear(&mut self) {
         self.objects.clear();
         self.bundles.clear();
+        self.diagnostics.clear();
     }
+    /// Errors collected in recovering mode since the last call, ranges are relative to the traced class string
+    #[inline]
+    pub fn take_diagnostics(&mut self) -> Vec<TailwindError> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+impl TailwindBuilder {
+    /// Attach the class prefix to a class name, after the variants and keeping the negative sign in front
+    ///
+    /// `-mt-2` => `-tw-mt-2`, `prose-headings:underline` => `prose-headings:tw-underline`
+    #[inline]
+    pub(crate) fn prefixed(&self, class: &str) -> String {
+        let (variants, utility) = split_variants(class);
+        match utility.strip_prefix('-') {
+            Some(rest) => format!("{}-{}{}", variants, self.prefix, rest),
+            None => format!("{}{}{}", variants, self.prefix, utility),
+        }
+    }
+}
+
+/// Split after the last `:` outside of brackets, `[color:red]` has no variant
+fn split_variants(class: &str) -> (&str, &str) {
+    let mut depth = 0;
+    let mut split = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ':' if depth == 0 => split = i + 1,
+            _ => {},
+        }
+    }
+    class.split_at(split)
+}
+
+impl TailwindBuilder {
+    /// Look up a theme value, as `theme('colors.red.500')` in css
+    ///
+    /// Supports `colors`, `screens`, `spacing`, `boxShadow`, `dropShadow`, `textShadow` and `fontFamily`.
+    pub fn get_theme(&self, path: &str) -> Result<String> {
+        self.find_theme(path.trim()).map_err(|_| TailwindError::syntax_error(format!("Unknown theme path: {}", path.trim())))
+    }
+    fn find_theme(&self, path: &str) -> Result<String> {
+        let keys: Vec<&str> = path.split('.').collect();
+        let out = match keys.as_slice() {
+            ["colors", name] => TailwindColor::parse(&[name], &TailwindArbitrary::from(""))?.get_properties(self),
+            ["colors", name, weight] => {
+                let color = TailwindColor::parse_themed(name, weight)?;
+                if let TailwindColor::Themed(name, weight) = &color {
+                    self.palettes.try_get_color(name, *weight)?;
+                }
+                color.get_properties(self)
+            },
+            ["screens", name] => format!("{}px", self.screens.try_get_width(name)?),
+            ["spacing", "px"] => "1px".to_string(),
+            // `spacing.0.5`
+            ["spacing", rest @ ..] => format!("{}rem", TailwindArbitrary::from(rest.join(".").as_str()).as_float()? / 4.0),
+            ["boxShadow"] | ["boxShadow", "DEFAULT"] => self.effects.get_box_shadow("")?,
+            ["boxShadow", name] => self.effects.get_box_shadow(name)?,
+            ["dropShadow"] | ["dropShadow", "DEFAULT"] => self.effects.get_drop_shadow("")?,
+            ["dropShadow", name] => self.effects.get_drop_shadow(name)?,
+            ["textShadow"] | ["textShadow", "DEFAULT"] => self.effects.get_text_shadow("")?,
+            ["textShadow", name] => self.effects.get_text_shadow(name)?,
+            ["fontFamily", name] if !self.fonts.get_family(name).is_empty() => self.fonts.get_family(name),
+            _ => return syntax_error!("Unknown theme path: {}", path),
+        };
+        Ok(out)
+    }
+    /// Replace every `theme(path)` or `theme(path, fallback)` call in the text
+    ///
+    /// The fallback is used when the path is unknown, otherwise that is an error.
+    pub fn resolve_theme(&self, text: &str) -> Result<String> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = find_function(rest, "theme(") {
+            let open = start + "theme".len();
+            let close = find_closing(rest, open)?;
+            out.push_str(&rest[..start]);
+            let args = &rest[open + 1..close];
+            let (path, fallback) = match split_arguments(args) {
+                Some((path, fallback)) => (path, Some(fallback)),
+                None => (args, None),
+            };
+            match (self.get_theme(unquote(path)), fallback) {
+                (Ok(o), _) => out.push_str(&o),
+                (Err(_), Some(fallback)) => out.push_str(fallback.trim()),
+                (Err(e), None) => return Err(e),
+            }
+            rest = &rest[close + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+    /// Media query of a breakpoint, as `@media screen(md)` in css
+    pub fn get_screen(&self, name: &str) -> Result<String> {
+        Ok(format!("(min-width: {}px)", self.screens.try_get_width(name.trim())?))
+    }
+    /// Declarations of utilities, as `@apply px-4 hover:underline;` in css
+    ///
+    /// Utilities with variants are written as nested rules, `&:hover{...}` or `@media (...){...}`.
+    pub fn apply(&self, classes: &str) -> Result<String> {
+        TailwindShortcut::get_nested(classes, self)
+    }
+}
+
+/// Start of `name(` that is not the tail of another identifier, e.g. `--theme(`
+pub fn find_function(text: &str, name: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(i) = text[offset..].find(name) {
+        let start = offset + i;
+        let word = text[..start].rsplit(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-')).next().unwrap_or("");
+        // `my-theme(` or `--theme(`, but not `100%-theme(` or `3-theme(`
+        let tail = match word.strip_suffix('-') {
+            Some(number) => !number.is_empty() && !number.chars().all(|c| c.is_ascii_digit()),
+            None => !word.is_empty(),
+        };
+        match tail {
+            true => offset = start + name.len(),
+            false => return Some(start),
+        }
+    }
+    None
+}
+
+/// Index of the bracket closing the `{` or `(` at `open`, quoted strings are skipped
+pub fn find_closing(text: &str, open: usize) -> Result<usize> {
+    let (left, right) = match text[open..].chars().next() {
+        Some('{') => ('{', '}'),
+        Some('(') => ('(', ')'),
+        _ => return syntax_error!("Expected `{{` or `(` in: {}", text),
+    };
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in text[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c == left => depth += 1,
+            (None, c) if c == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(open + i);
+                }
+            },
+            _ => {},
+        }
+    }
+    syntax_error!("Unclosed `{}` in: {}", left, text)
+}
+
+/// `'colors.red.500', currentColor` => `('colors.red.500', currentColor)`
+fn split_arguments(args: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    let mut depth = 0;
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => return Some((&args[..i], &args[i + 1..])),
+            _ => {},
+        }
+    }
+    None
+}
+
+/// `'colors.red.500'` => `colors.red.500`
+pub fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    for q in ['"', '\''] {
+        if let Some(s) = s.strip_prefix(q).and_then(|s| s.strip_suffix(q)) {
+            return s;
+        }
+    }
+    s
 }
@@ -2,9 +2,13 @@ use std::{collections::BTreeSet, fmt::Debug};
 
 use crate::{systems::instruction::TailwindInstruction, *};
 
-pub use self::base62::{Base62, BASE62};
+pub use self::{
+    base62::{Base62, BASE62},
+    methods::{find_closing, find_function, unquote},
+};
 
 mod base62;
+mod merge;
 mod methods;
 mod setter;
 
@@ -29,6 +33,25 @@ pub struct TailwindBuilder {
     ///
     /// Only determined when packing
     pub effects: EffectSystem,
+    /// Strategies of the forms extension, both disabled by default
+    pub forms: FormSystem,
+    /// Prefix required before every utility, e.g. `tw-` for `tw-mb-4`, `hover:tw-p-2` and `-tw-mt-2`
+    ///
+    /// Classes without the prefix will not be recognised
+    pub prefix: String,
+    /// Custom utilities, see [`TailwindPlugin`]
+    pub plugins: PluginSystem,
+    /// Classes composed from other utilities, see [`ShortcutSystem`]
+    pub shortcuts: ShortcutSystem,
+    /// Classes always emitted by the bundle, see [`SafelistSystem`]
+    pub safelist: SafelistSystem,
+    /// Classes never generated, even if they appear in the content
+    pub blocklist: BTreeSet<String>,
+    /// Keep going after an invalid class, the error is collected instead of returned
+    ///
+    /// See [`TailwindBuilder::take_diagnostics`]
+    pub recover: bool,
+    pub(crate) diagnostics: Vec<TailwindError>,
     pub(crate) objects: BTreeSet<CssInstance>,
     pub(crate) bundles: BTreeSet<CssBundle>,
 }
@@ -151,11 +174,23 @@ impl TailwindBuilder {
     }
     /// Bundle all used stylesheets
     pub fn bundle(&self) -> Result<String> {
-        let mut out = String::with_capacity(1024 * 10);
+        let mut out = self.bundle_base();
+        out.push_str(&self.bundle_utilities()?);
+        Ok(out)
+    }
+    /// The preflight part of the bundle, `@tailwind base`
+    pub fn bundle_base(&self) -> String {
+        let mut out = String::new();
         if !self.preflight.disable {
             out.push_str(&self.preflight.to_string());
+            out.push_str(&self.forms.get_preflight(self));
         }
-        for item in &self.objects {
+        out
+    }
+    /// The used utilities part of the bundle, `@tailwind utilities`
+    pub fn bundle_utilities(&self) -> Result<String> {
+        let mut out = String::with_capacity(1024 * 10);
+        for item in &self.get_objects()? {
             item.write_css(&mut out)?;
         }
         for item in &self.bundles {
@@ -165,16 +200,102 @@ impl TailwindBuilder {
     }
 }
 
-fn parse_tailwind(input: &str) -> Result<Vec<TailwindInstruction>> {
-    let styles = tailwind_ast::parse_tailwind(input)?;
+impl TailwindBuilder {
+    /// Traced objects together with the safelisted classes
+    fn get_objects(&self) -> Result<BTreeSet<CssInstance>> {
+        let mut objects = self.objects.clone();
+        for class in self.safelist.get_classes() {
+            for item in parse_tailwind(class)? {
+                if self.is_blocked(&item) {
+                    continue;
+                }
+                objects.insert(CssInstance::new(&*item.get_instance(self)?, self, false));
+            }
+        }
+        for class in self.safelist.get_candidates() {
+            let parsed = match parse_tailwind(class) {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            for item in parsed {
+                if self.is_blocked(&item) {
+                    continue;
+                }
+                if let Ok(instance) = item.get_instance(self) {
+                    objects.insert(CssInstance::new(&*instance, self, false));
+                }
+            }
+        }
+        Ok(objects)
+    }
+    fn is_blocked(&self, item: &TailwindInstruction) -> bool {
+        !self.blocklist.is_empty() && self.blocklist.contains(&item.to_string())
+    }
+}
+
+pub(crate) fn parse_tailwind(input: &str) -> Result<Vec<TailwindInstruction>> {
+    let styles = match tailwind_ast::parse_tailwind(input) {
+        Ok(o) => o,
+        Err(e) => {
+            let rest = match &e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => Some(e.input),
+                nom::Err::Incomplete(_) => None,
+            };
+            let mut error = TailwindError::from(e);
+            // the class where parsing stopped
+            if let Some(start) = rest.and_then(|s| (s.as_ptr() as usize).checked_sub(input.as_ptr() as usize)).filter(|s| *s <= input.len()) {
+                let end = input[start..].find(char::is_whitespace).map_or(input.len(), |n| start + n);
+                error.set_range(start, end);
+            }
+            return Err(error);
+        },
+    };
     Ok(styles.into_iter().map(TailwindInstruction::from).collect())
 }
 
+/// In recovering mode, invalid classes are reported and kept as written
+fn parse_classes(tw: &mut TailwindBuilder, input: &str, out: &mut CssBundle) -> Result<Vec<TailwindInstruction>> {
+    if !tw.recover {
+        return parse_tailwind(input);
+    }
+    let (styles, diagnostics) = tailwind_ast::parse_tailwind_recover(input);
+    for diagnostic in diagnostics {
+        out.add_class(input[diagnostic.span.clone()].to_string());
+        let mut error = TailwindError::syntax_error(diagnostic.message);
+        error.set_range(diagnostic.span.start, diagnostic.span.end);
+        tw.diagnostics.push(error);
+    }
+    Ok(styles.into_iter().map(TailwindInstruction::from).collect())
+}
+
+/// In recovering mode, unknown utilities are reported and `None` is returned
+fn get_instance(tw: &mut TailwindBuilder, item: &TailwindInstruction) -> Result<Option<Box<dyn TailwindInstance>>> {
+    match item.get_instance(tw) {
+        Ok(o) => Ok(Some(o)),
+        Err(e) if tw.recover => {
+            tw.diagnostics.push(e);
+            Ok(None)
+        },
+        Err(e) => Err(e),
+    }
+}
+
 fn try_trace(tw: &mut TailwindBuilder, style: &str, obfuscate: bool) -> Result<CssBundle> {
-    let parsed = parse_tailwind(style)?;
     let mut out = CssBundle::default();
+    let parsed = parse_classes(tw, style, &mut out)?;
     for item in parsed {
-        let i = CssInstance::new(&*item.get_instance()?, tw, obfuscate);
+        if tw.is_blocked(&item) {
+            out.add_class(item.to_string());
+            continue;
+        }
+        let instance = match get_instance(tw, &item)? {
+            Some(s) => s,
+            None => {
+                out.add_class(item.to_string());
+                continue;
+            },
+        };
+        let i = CssInstance::new(&*instance, tw, obfuscate);
         out.add_trace(&i);
         tw.objects.insert(i);
     }
@@ -182,10 +303,21 @@ fn try_trace(tw: &mut TailwindBuilder, style: &str, obfuscate: bool) -> Result<C
 }
 
 fn try_inline(tw: &mut TailwindBuilder, style: &str, mode: CssInlineMode) -> Result<CssBundle> {
-    let parsed = parse_tailwind(style)?;
     let mut out = CssBundle::default();
+    let parsed = parse_classes(tw, style, &mut out)?;
     for item in parsed {
-        let i = CssInstance::new(&*item.get_instance()?, tw, true);
+        if tw.is_blocked(&item) {
+            out.add_class(item.to_string());
+            continue;
+        }
+        let instance = match get_instance(tw, &item)? {
+            Some(s) => s,
+            None => {
+                out.add_class(item.to_string());
+                continue;
+            },
+        };
+        let i = CssInstance::new(&*instance, tw, true);
         match &i.inlineable {
             true => out.add_inline(i),
             false => {
@@ -9,6 +9,38 @@ impl TailwindBuilder {
     pub fn preflight_addition(&mut self, custom: impl Into<String>) {
         self.preflight.custom = custom.into()
     }
+    /// Config for the forms extension
+    pub fn forms(&mut self) -> &mut FormSystem {
+        &mut self.forms
+    }
+    /// Set the class prefix, e.g. `tw-`
+    pub fn set_prefix(&mut self, prefix: impl Into<String>) {
+        self.prefix = prefix.into()
+    }
+    /// Register a class composed from other utilities, e.g. `btn` => `px-4 py-2 rounded-md`
+    pub fn add_shortcut(&mut self, name: impl Into<String>, classes: impl Into<String>) {
+        self.shortcuts.insert(name, classes)
+    }
+    /// Register a custom utility, consulted before the builtin utilities
+    pub fn add_plugin(&mut self, plugin: impl TailwindPlugin + 'static) {
+        self.plugins.register_before(plugin)
+    }
+    /// Register a custom utility, consulted only when no builtin utility matches
+    pub fn add_fallback_plugin(&mut self, plugin: impl TailwindPlugin + 'static) {
+        self.plugins.register_after(plugin)
+    }
+    /// Always emit the class, e.g. `bg-red-500`
+    pub fn add_safelist(&mut self, class: impl Into<String>) {
+        self.safelist.insert(class)
+    }
+    /// Always emit the classes matched by the pattern, e.g. `bg-(red|blue)-500` with variants `["hover"]`
+    pub fn add_safelist_pattern(&mut self, pattern: &str, variants: &[&str]) -> Result<()> {
+        self.safelist.insert_pattern(pattern, variants)
+    }
+    /// Never generate the class, e.g. `container`
+    pub fn add_blocklist(&mut self, class: impl Into<String>) {
+        self.blocklist.insert(class.into());
+    }
 }
 
 impl Default for TailwindBuilder {
@@ -21,6 +53,14 @@ impl Default for TailwindBuilder {
             fonts: FontSystem::builtin(),
             preflight: PreflightSystem::default(),
             effects: EffectSystem::builtin(),
+            forms: FormSystem::default(),
+            prefix: String::new(),
+            plugins: PluginSystem::default(),
+            shortcuts: ShortcutSystem::default(),
+            safelist: SafelistSystem::default(),
+            blocklist: BTreeSet::default(),
+            recover: false,
+            diagnostics: vec![],
         }
     }
 }
@@ -54,7 +54,11 @@ impl TailwindColor {
     }
     #[inline]
     pub fn parse_arbitrary(arbitrary: &TailwindArbitrary) -> Result<TailwindColor> {
-        Ok(Self::RGB(arbitrary.as_color()?))
+        match arbitrary.get_hint() {
+            None => Ok(Self::RGB(arbitrary.as_color()?)),
+            Some("color") => Ok(Self::Arbitrary(TailwindArbitrary::new(arbitrary)?)),
+            Some(hint) => syntax_error!("Expected a color, found {}: {}", hint, arbitrary.get_class()),
+        }
     }
     ///
     #[inline]
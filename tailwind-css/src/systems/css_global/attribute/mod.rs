@@ -8,9 +8,6 @@ mod traits;
 #[derive(Debug, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct CssAttributes {
     normal: ImportantMap,
-    transforms: ImportantSet,
-    backdrop_filter: ImportantSet,
-    filter: ImportantSet,
 }
 
 impl CssAttributes {
@@ -26,13 +23,7 @@ impl CssAttributes {
         K: Into<String>,
         V: Into<String>,
     {
-        let key = key.into();
-        match key.as_str() {
-            "transform" => self.transforms.insert(value.into()),
-            "backdrop-filter" => self.backdrop_filter.insert(value.into()),
-            "filter" => self.filter.insert(value.into()),
-            _ => self.normal.insert(key, value.into()),
-        };
+        self.normal.insert(key.into(), value.into());
     }
 
     /// # Arguments
@@ -48,4 +39,9 @@ impl CssAttributes {
             self.insert(i.0, i.1);
         }
     }
+
+    /// Names of all css properties
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.normal.keys()
+    }
 }
@@ -18,6 +18,10 @@ impl CssBundle {
     pub fn add_trace(&mut self, item: &CssInstance) {
         self.non_inlined_classes.insert(item.get_class());
     }
+    /// keep a class without generating css for it
+    pub fn add_class(&mut self, class: String) {
+        self.non_inlined_classes.insert(class);
+    }
     /// insert new css instance to the html tag
     pub fn add_inline(&mut self, item: CssInstance) {
         self.attribute += item.attribute;
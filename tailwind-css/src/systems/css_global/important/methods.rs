@@ -1,25 +1,5 @@
 use super::*;
 
-impl ImportantSet {
-    pub fn insert<T>(&mut self, value: T) -> bool
-    where
-        T: Into<String>,
-    {
-        self.important = false;
-        self.set.insert(value.into())
-    }
-    pub fn insert_important<T>(&mut self, value: T) -> bool
-    where
-        T: Into<String>,
-    {
-        self.important = true;
-        self.set.insert(value.into())
-    }
-    pub fn is_empty(&self) -> bool {
-        self.set.is_empty()
-    }
-}
-
 impl ImportantMap {
     pub fn insert<K, V>(&mut self, key: K, value: V) -> bool
     where
@@ -35,4 +15,7 @@ impl ImportantMap {
     {
         self.map.insert(key.into(), (true, value.into())).is_some()
     }
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.map.keys().map(|s| s.as_str())
+    }
 }
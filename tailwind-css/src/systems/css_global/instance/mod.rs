@@ -9,6 +9,8 @@ pub(crate) struct CssInstance {
     pub inlineable: bool,
     pub obfuscate: bool,
     pub selector: String,
+    /// Selector tail after the class itself, e.g. `>:not([hidden])~:not([hidden])`
+    pub combinator: String,
     pub attribute: CssAttributes,
     pub addition: String,
 }
@@ -19,14 +21,28 @@ impl CssInstance {
         Self {
             obfuscate,
             inlineable: item.inlineable(),
-            selector: item.id(),
+            selector: ctx.prefixed(&item.id()),
+            combinator: Self::get_combinator(item, ctx),
             attribute: item.attributes(ctx),
             addition: item.additional(ctx),
         }
     }
 
+    /// Everything [`TailwindInstance::selectors`] puts after `.{id}`, so that prefix and obfuscation still apply to the class
+    pub fn get_combinator(item: &dyn TailwindInstance, ctx: &TailwindBuilder) -> String {
+        let selectors = item.selectors(ctx);
+        match selectors.strip_prefix(&format!(".{}", item.id())) {
+            Some(s) => s.to_string(),
+            None => String::new(),
+        }
+    }
+
     pub fn obfuscate(css: &Self) -> String {
         let mut hasher = Xxh3::new();
+        // plain utilities keep their names, `divide-red-500` must not collide with `border-red-500`
+        if !css.combinator.is_empty() {
+            css.combinator.hash(&mut hasher);
+        }
         css.attribute.hash(&mut hasher);
         css.addition.hash(&mut hasher);
         hasher.finish().base62()
@@ -41,10 +57,15 @@ impl CssInstance {
     pub fn write_css(&self, f: &mut (dyn Write)) -> Result<()> {
         f.write_char('.')?;
         normalize_class_name(f, &self.get_class())?;
+        f.write_str(&self.combinator)?;
         f.write_char('{')?;
         write!(f, "{}", self.attribute)?;
         f.write_char('}')?;
-        write!(f, "{}", self.addition)?;
+        match self.obfuscate {
+            // additional rules are written against the readable class
+            true => write!(f, "{}", self.addition.replace(&format!(".{}", self.selector), &format!(".{}", self.get_class())))?,
+            false => write!(f, "{}", self.addition)?,
+        }
         Ok(())
     }
 }
@@ -13,7 +13,7 @@ use crate::{Result, TailwindBuilder, TailwindInstance};
 
 pub use self::{
     attribute::CssAttributes,
-    important::{ImportantMap, ImportantSet},
+    important::ImportantMap,
     mode::CssInlineMode,
 };
 pub(crate) use self::{bundle::CssBundle, instance::CssInstance};
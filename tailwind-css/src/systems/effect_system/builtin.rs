@@ -5,6 +5,7 @@ impl EffectSystem {
         let mut new = Self::default();
         new.builtin_box_shadows();
         new.builtin_drop_shadows();
+        new.builtin_text_shadows();
         new
     }
     fn builtin_box_shadows(&mut self) {
@@ -18,12 +19,21 @@ impl EffectSystem {
         self.insert_box_shadow("none", "0 0 #0000");
     }
     fn builtin_drop_shadows(&mut self) {
-        self.set_drop_shadow_default("drop-shadow(0 1px 2px rgb(0 0 0 / 0.1)) drop-shadow(0 1px 1px rgb(0 0 0 / 0.06))");
-        self.insert_drop_shadow("sm", "drop-shadow(0 1px 1px rgb(0 0 0 / 0.05));");
-        self.insert_drop_shadow("md", "drop-shadow(0 4px 3px rgb(0 0 0 / 0.07)) drop-shadow(0 2px 2px rgb(0 0 0 / 0.06));");
-        self.insert_drop_shadow("lg", "drop-shadow(0 10px 8px rgb(0 0 0 / 0.04)) drop-shadow(0 4px 3px rgb(0 0 0 / 0.1));");
-        self.insert_drop_shadow("xl", "drop-shadow(0 20px 13px rgb(0 0 0 / 0.03)) drop-shadow(0 8px 5px rgb(0 0 0 / 0.08));");
-        self.insert_drop_shadow("2xl", "drop-shadow(0 25px 25px rgb(0 0 0 / 0.15));");
-        self.insert_drop_shadow("none", "drop-shadow(0 0 #0000);");
+        self.set_drop_shadow_default("0 1px 2px rgb(0 0 0 / 0.1), 0 1px 1px rgb(0 0 0 / 0.06)");
+        self.insert_drop_shadow("sm", "0 1px 1px rgb(0 0 0 / 0.05)");
+        self.insert_drop_shadow("md", "0 4px 3px rgb(0 0 0 / 0.07), 0 2px 2px rgb(0 0 0 / 0.06)");
+        self.insert_drop_shadow("lg", "0 10px 8px rgb(0 0 0 / 0.04), 0 4px 3px rgb(0 0 0 / 0.1)");
+        self.insert_drop_shadow("xl", "0 20px 13px rgb(0 0 0 / 0.03), 0 8px 5px rgb(0 0 0 / 0.08)");
+        self.insert_drop_shadow("2xl", "0 25px 25px rgb(0 0 0 / 0.15)");
+        self.insert_drop_shadow("none", "0 0 #0000");
+    }
+    fn builtin_text_shadows(&mut self) {
+        self.set_text_shadow_default("0px 1px 1px rgb(0 0 0 / 0.1), 0px 1px 2px rgb(0 0 0 / 0.1), 0px 2px 4px rgb(0 0 0 / 0.1)");
+        self.insert_text_shadow("2xs", "0px 1px 0px rgb(0 0 0 / 0.15)");
+        self.insert_text_shadow("xs", "0px 1px 1px rgb(0 0 0 / 0.2)");
+        self.insert_text_shadow("sm", "0px 1px 0px rgb(0 0 0 / 0.075), 0px 1px 1px rgb(0 0 0 / 0.075), 0px 2px 2px rgb(0 0 0 / 0.075)");
+        self.insert_text_shadow("md", "0px 1px 1px rgb(0 0 0 / 0.1), 0px 1px 2px rgb(0 0 0 / 0.1), 0px 2px 4px rgb(0 0 0 / 0.1)");
+        self.insert_text_shadow("lg", "0px 1px 2px rgb(0 0 0 / 0.1), 0px 3px 2px rgb(0 0 0 / 0.1), 0px 4px 8px rgb(0 0 0 / 0.1)");
+        self.insert_text_shadow("none", "0 0 #0000");
     }
 }
@@ -0,0 +1,83 @@
+use super::*;
+
+/// One comma separated layer of a shadow preset, `inset? <offset-x> <offset-y> <blur>? <spread>? <color>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShadowLayer {
+    /// `inset` keyword, box shadows only
+    pub inset: bool,
+    /// Offsets, blur and spread radius
+    pub geometry: String,
+    /// The color the preset is drawn with
+    pub color: String,
+}
+
+impl Display for ShadowLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.inset {
+            f.write_str("inset ")?;
+        }
+        write!(f, "{} {}", self.geometry, self.color)
+    }
+}
+
+impl ShadowLayer {
+    /// `0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1)`
+    ///
+    /// Only top-level commas separate layers, so `rgba(0, 0, 0, 0.3)` stays in one piece.
+    pub fn parse_layers(input: &str) -> Vec<Self> {
+        split_top_level(input, ',').into_iter().filter_map(Self::parse).collect()
+    }
+    /// `inset 0 2px 4px 0 rgb(0 0 0 / 0.05)`
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let (inset, rest) = match input.strip_prefix("inset ") {
+            Some(s) => (true, s.trim_start()),
+            None => (false, input),
+        };
+        let tokens = split_top_level(rest, ' ');
+        let (color, geometry) = tokens.split_last()?;
+        if geometry.is_empty() {
+            return None;
+        }
+        Some(Self { inset, geometry: geometry.join(" "), color: color.to_string() })
+    }
+    /// Alpha of the preset color, as in `rgba(0, 0, 0, 0.3)` or `hsl(0 0% 0% / 10%)`, unknown colors are opaque
+    pub fn get_alpha(&self) -> f32 {
+        Srgb::from_str(&self.color).map(|c| c.alpha).unwrap_or(1.0)
+    }
+    /// The preset color without its alpha, unknown colors are kept as they are
+    pub fn get_opaque(&self) -> String {
+        match Srgb::from_str(&self.color) {
+            Ok(c) => format!("rgba({}, {}, {}, 1)", 255.0 * c.red, 255.0 * c.green, 255.0 * c.blue),
+            Err(_) => self.color.clone(),
+        }
+    }
+    /// The same layer drawn with `var(variable)`, scaled by the alpha of the preset
+    ///
+    /// Falls back to the preset color while `variable` is unset, so a shadow color class only has to set the variable.
+    pub fn get_colored(&self, variable: &str) -> String {
+        let alpha = (self.get_alpha() * 100.0 * 1000.0).round() / 1000.0;
+        let color = format!("color-mix(in srgb, var({}, {}) {}%, transparent)", variable, self.get_opaque(), alpha);
+        Self { color, ..self.clone() }.to_string()
+    }
+}
+
+/// Split on `sep` outside of parentheses
+fn split_top_level(input: &str, sep: char) -> Vec<&str> {
+    let mut out = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                out.push(&input[start..i]);
+                start = i + c.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    out.push(&input[start..]);
+    out.into_iter().map(str::trim).filter(|s| !s.is_empty()).collect()
+}
@@ -1,46 +1,121 @@
-use std::collections::BTreeMap;
+use crate::{syntax_error, Result};
+use css_color::Srgb;
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+pub use self::layer::ShadowLayer;
 
 mod builtin;
+mod layer;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct EffectSystem {
-    box_shadow_default: String,
-    box_shadows: BTreeMap<String, String>,
-    drop_shadow_default: String,
-    drop_shadows: BTreeMap<String, String>,
+    box_shadow_default: Vec<ShadowLayer>,
+    box_shadows: BTreeMap<String, Vec<ShadowLayer>>,
+    drop_shadow_default: Vec<ShadowLayer>,
+    drop_shadows: BTreeMap<String, Vec<ShadowLayer>>,
+    text_shadow_default: Vec<ShadowLayer>,
+    text_shadows: BTreeMap<String, Vec<ShadowLayer>>,
 }
 
 impl EffectSystem {
-    pub fn get_box_shadow(&self, name: &str) -> String {
-        self.box_shadows.get(name).unwrap_or(&self.box_shadow_default).clone()
+    /// `0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1)`
+    pub fn get_box_shadow(&self, name: &str) -> Result<String> {
+        let layers = self.get_box_shadow_layers(name)?;
+        Ok(layers.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "))
+    }
+    /// Layers of a preset, the empty name is the `DEFAULT` one
+    pub fn get_box_shadow_layers(&self, name: &str) -> Result<&[ShadowLayer]> {
+        match name {
+            "" => Ok(&self.box_shadow_default),
+            _ => match self.box_shadows.get(name) {
+                Some(s) => Ok(s),
+                None => syntax_error!("no such box shadow: {}", name),
+            },
+        }
     }
+    pub fn has_box_shadow(&self, name: &str) -> bool {
+        self.box_shadows.contains_key(name)
+    }
+    /// Layers are separated by top-level `,`, as in `box-shadow`
     pub fn insert_box_shadow<K, V>(&mut self, key: K, value: V)
     where
         K: Into<String>,
-        V: Into<String>,
+        V: AsRef<str>,
     {
-        self.box_shadows.insert(key.into(), value.into());
+        self.box_shadows.insert(key.into(), ShadowLayer::parse_layers(value.as_ref()));
     }
     pub fn set_box_shadow_default<V>(&mut self, value: V)
     where
-        V: Into<String>,
+        V: AsRef<str>,
     {
-        self.box_shadow_default = value.into();
+        self.box_shadow_default = ShadowLayer::parse_layers(value.as_ref());
+    }
+    /// `drop-shadow(0 1px 2px rgb(0 0 0 / 0.1)) drop-shadow(0 1px 1px rgb(0 0 0 / 0.06))`
+    pub fn get_drop_shadow(&self, name: &str) -> Result<String> {
+        let layers = self.get_drop_shadow_layers(name)?;
+        Ok(layers.iter().map(|s| format!("drop-shadow({})", s)).collect::<Vec<_>>().join(" "))
     }
-    pub fn get_drop_shadow(&self, name: &str) -> String {
-        self.box_shadows.get(name).unwrap_or(&self.drop_shadow_default).clone()
+    /// Layers of a preset, the empty name is the `DEFAULT` one
+    pub fn get_drop_shadow_layers(&self, name: &str) -> Result<&[ShadowLayer]> {
+        match name {
+            "" => Ok(&self.drop_shadow_default),
+            _ => match self.drop_shadows.get(name) {
+                Some(s) => Ok(s),
+                None => syntax_error!("no such drop shadow: {}", name),
+            },
+        }
     }
+    pub fn has_drop_shadow(&self, name: &str) -> bool {
+        self.drop_shadows.contains_key(name)
+    }
+    /// Layers are separated by top-level `,`, each becomes one `drop-shadow()`
     pub fn insert_drop_shadow<K, V>(&mut self, key: K, value: V)
     where
         K: Into<String>,
-        V: Into<String>,
+        V: AsRef<str>,
     {
-        self.drop_shadows.insert(key.into(), value.into());
+        self.drop_shadows.insert(key.into(), ShadowLayer::parse_layers(value.as_ref()));
     }
     pub fn set_drop_shadow_default<V>(&mut self, value: V)
     where
-        V: Into<String>,
+        V: AsRef<str>,
+    {
+        self.drop_shadow_default = ShadowLayer::parse_layers(value.as_ref());
+    }
+    /// `0px 1px 0px rgb(0 0 0 / 0.075), 0px 1px 1px rgb(0 0 0 / 0.075), 0px 2px 2px rgb(0 0 0 / 0.075)`
+    pub fn get_text_shadow(&self, name: &str) -> Result<String> {
+        let layers = self.get_text_shadow_layers(name)?;
+        Ok(layers.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "))
+    }
+    /// Layers of a preset, the empty name is the `DEFAULT` one
+    pub fn get_text_shadow_layers(&self, name: &str) -> Result<&[ShadowLayer]> {
+        match name {
+            "" => Ok(&self.text_shadow_default),
+            _ => match self.text_shadows.get(name) {
+                Some(s) => Ok(s),
+                None => syntax_error!("no such text shadow: {}", name),
+            },
+        }
+    }
+    pub fn has_text_shadow(&self, name: &str) -> bool {
+        self.text_shadows.contains_key(name)
+    }
+    /// Layers are separated by top-level `,`, as in `text-shadow`
+    pub fn insert_text_shadow<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: AsRef<str>,
+    {
+        self.text_shadows.insert(key.into(), ShadowLayer::parse_layers(value.as_ref()));
+    }
+    pub fn set_text_shadow_default<V>(&mut self, value: V)
+    where
+        V: AsRef<str>,
     {
-        self.drop_shadow_default = value.into();
+        self.text_shadow_default = ShadowLayer::parse_layers(value.as_ref());
     }
 }
@@ -0,0 +1,26 @@
+use crate::{TailwindBuilder, TailwindForm};
+
+/// <https://github.com/tailwindlabs/tailwindcss-forms#using-classes-to-style>
+#[derive(Clone, Debug, Default)]
+pub struct FormSystem {
+    /// ## Base strategy
+    /// Reset `input`, `select`, `textarea`, checkboxes and radios by element selectors in the preflight.
+    pub base: bool,
+    /// ## Class strategy
+    /// Enable the `form-input`, `form-textarea`, `form-select`, `form-checkbox` and `form-radio` classes.
+    pub class: bool,
+}
+
+impl FormSystem {
+    /// Both strategies, like the default of the official plugin
+    pub fn builtin() -> Self {
+        Self { base: true, class: true }
+    }
+    /// Element selector rules written after the preflight
+    pub fn get_preflight(&self, ctx: &TailwindBuilder) -> String {
+        match self.base {
+            true => TailwindForm::get_base(ctx),
+            false => String::new(),
+        }
+    }
+}
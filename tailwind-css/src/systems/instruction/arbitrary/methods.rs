@@ -11,34 +11,57 @@ impl TailwindArbitrary {
     }
     #[inline]
     pub fn as_str(&self) -> &str {
-        self.inner.as_ref()
+        self.view_value()
+    }
+    /// The value as written, `[color:red]` is a property and not a hinted color
+    #[inline]
+    pub fn as_raw(&self) -> &str {
+        &self.inner
     }
     #[inline]
     pub fn as_integer(&self) -> Result<i32> {
-        Ok(i32::from_str(&self.inner)?)
+        Ok(i32::from_str(self.view_value())?)
     }
     #[inline]
     pub fn as_float(&self) -> Result<f32> {
-        Ok(f32::from_str(&self.inner)?)
+        Ok(f32::from_str(self.view_value())?)
     }
     #[inline]
     pub fn as_fraction(&self) -> Result<(usize, usize)> {
-        Ok(parse_fraction(&self.inner)?.1)
+        Ok(parse_fraction(self.view_value())?.1)
     }
     #[inline]
     pub fn as_length(&self) -> Result<LengthUnit> {
-        LengthUnit::parse_length(&self.inner)
+        LengthUnit::parse_length(self.view_value())
     }
     #[inline]
     pub fn as_length_or_fraction(&self) -> Result<LengthUnit> {
-        LengthUnit::parse_length(&self.inner).or_else(|_| LengthUnit::parse_faction(&self.inner))
+        LengthUnit::parse_length(self.view_value()).or_else(|_| LengthUnit::parse_faction(self.view_value()))
     }
     #[inline]
     pub fn as_angle(&self) -> Result<LengthUnit> {
-        LengthUnit::parse_angle(&self.inner)
+        LengthUnit::parse_angle(self.view_value())
     }
     #[inline]
     pub fn as_color(&self) -> Result<Srgb> {
-        Ok(Srgb::from_str(&self.inner)?)
+        Ok(Srgb::from_str(self.view_value())?)
+    }
+    /// The type hint written before the value, e.g. `length` in `text-[length:var(--size)]`
+    ///
+    /// - https://tailwindcss.com/docs/adding-custom-styles#resolving-ambiguities
+    #[inline]
+    pub fn get_hint(&self) -> Option<&str> {
+        let (hint, _) = self.inner.split_once(':')?;
+        TYPE_HINTS.contains(&hint).then_some(hint)
+    }
+    /// The value without its type hint
+    #[inline]
+    pub(super) fn view_value(&self) -> &str {
+        match self.get_hint() {
+            Some(hint) => &self.inner[hint.len() + 1..],
+            None => &self.inner,
+        }
     }
 }
+
+const TYPE_HINTS: &[&str] = &["length", "color", "url", "image", "percentage", "number", "position", "family-name"];
@@ -6,12 +6,14 @@ mod methods;
 #[derive(Debug, Clone)]
 pub struct TailwindArbitrary {
     inner: Box<str>,
+    /// Written text when `inner` was rewritten, e.g. by `theme(...)`
+    class: Option<Box<str>>,
 }
 
 impl Display for TailwindArbitrary {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_char('[')?;
-        for c in self.inner.chars() {
+        for c in self.view_class().chars() {
             match c {
                 ' ' => f.write_char('_')?,
                 _ => f.write_char(c)?,
@@ -23,13 +25,13 @@ impl Display for TailwindArbitrary {
 
 impl From<&str> for TailwindArbitrary {
     fn from(s: &str) -> Self {
-        Self { inner: Box::from(s) }
+        Self { inner: Box::from(s), class: None }
     }
 }
 
 impl From<&Self> for TailwindArbitrary {
     fn from(s: &Self) -> Self {
-        Self { inner: s.inner.clone() }
+        Self { inner: s.inner.clone(), class: s.class.clone() }
     }
 }
 
@@ -39,22 +41,17 @@ impl TailwindArbitrary {
         T: Into<Self>,
     {
         let out = s.into();
-        if cfg!(compile_time) {
-            if out.inner.is_empty() {
-                return Err(TailwindError::syntax_error("Arbitrary value cannot be empty"));
-            }
-            // TODO: Check unbalanced quotes
-            if out.inner.contains('\n') {
-                return Err(TailwindError::syntax_error("Arbitrary value does balance quotes"));
-            }
+        if cfg!(feature = "compile_time") && out.inner.is_empty() {
+            return Err(TailwindError::syntax_error("Arbitrary value cannot be empty"));
         }
+        check_balanced(&out.inner)?;
         Ok(out)
     }
 
     pub fn get_class(&self) -> String {
-        let mut class = String::with_capacity(self.inner.len() + 2);
+        let mut class = String::with_capacity(self.view_class().len() + 2);
         class.push('[');
-        for c in self.inner.chars() {
+        for c in self.view_class().chars() {
             match c {
                 ' ' => class.push('_'),
                 _ => class.push(c),
@@ -69,7 +66,130 @@ impl TailwindArbitrary {
     pub fn write_class(&self, f: &mut Formatter, before: &str) -> std::fmt::Result {
         write!(f, "{}{}", before, self.get_class())
     }
+    /// The css value, `_` is written as a space and `\_` as an underscore, except inside `url(...)`
+    ///
+    /// Operators of math functions are spaced out, `calc(100%-1rem)` => `calc(100% - 1rem)`
     pub fn get_properties(&self) -> String {
-        self.inner.to_string()
+        css_value(self.view_value())
+    }
+    /// The css value without reading a type hint, as the value of `[--brand:color:red]`
+    pub fn get_raw_properties(&self) -> String {
+        css_value(self.as_raw())
+    }
+    /// Resolve `theme(path)` calls, the class keeps the written text
+    ///
+    /// e.g. `w-[calc(100%-theme(spacing.4))]` => `width: calc(100% - 1rem)`
+    pub fn resolve_theme(&self, ctx: &TailwindBuilder) -> Result<Self> {
+        if !self.inner.contains("theme(") {
+            return Ok(self.clone());
+        }
+        Ok(Self { inner: Box::from(ctx.resolve_theme(&self.inner)?), class: Some(self.view_class().into()) })
+    }
+    fn view_class(&self) -> &str {
+        self.class.as_deref().unwrap_or(&self.inner)
+    }
+}
+
+fn css_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("url(") {
+            let end = rest.find(')').map_or(rest.len(), |i| i + 1);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        match c {
+            '_' => out.push(' '),
+            '\\' if rest[1..].starts_with('_') => {
+                out.push('_');
+                rest = &rest[1..];
+            },
+            _ => out.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    normalize_math(&out)
+}
+
+/// Quotes and brackets outside of quotes must be balanced
+fn check_balanced(value: &str) -> Result<()> {
+    let mut brackets = vec![];
+    let mut quote = None;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => {
+                chars.next();
+            },
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(') => brackets.push(')'),
+            (None, '[') => brackets.push(']'),
+            (None, '{') => brackets.push('}'),
+            (None, ')' | ']' | '}') if brackets.pop() != Some(c) => {
+                return syntax_error!("Arbitrary value has unbalanced brackets: {}", value);
+            },
+            _ => {},
+        }
+    }
+    if quote.is_some() {
+        return syntax_error!("Arbitrary value has unbalanced quotes: {}", value);
+    }
+    if !brackets.is_empty() {
+        return syntax_error!("Arbitrary value has unbalanced brackets: {}", value);
+    }
+    Ok(())
+}
+
+/// Space out `+ - * /` inside every math function, as tailwind does
+///
+/// `var(--name`, `[line-name]` and keywords such as `min-content` are kept as written,
+/// a sign after `(`, `,` or another operator is not an operator.
+fn normalize_math(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 8);
+    let mut rest = value;
+    // `minmax(` is not `max(`
+    while let Some(start) = ["calc(", "min(", "max(", "clamp("].iter().filter_map(|f| find_function(rest, f)).min() {
+        let open = start + rest[start..].find('(').unwrap_or(0);
+        let close = match find_closing(rest, open) {
+            Ok(close) => close,
+            Err(_) => break,
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&space_operators(&rest[start..=close]));
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn space_operators(function: &str) -> String {
+    const KEYWORDS: [&str; 4] = ["min-content", "max-content", "fit-content", "safe-area-inset-"];
+    let mut math = String::with_capacity(function.len() + 8);
+    let mut rest = function;
+    while let Some(c) = rest.chars().next() {
+        let keep = match rest {
+            _ if rest.starts_with("var(") => rest.find([')', ',']).unwrap_or(rest.len()),
+            _ if rest.starts_with('[') => rest.find(']').unwrap_or(rest.len()),
+            _ => KEYWORDS.iter().find(|k| rest.starts_with(*k)).map_or(0, |k| k.len()),
+        };
+        if keep > 0 {
+            math.push_str(&rest[..keep]);
+            rest = &rest[keep..];
+            continue;
+        }
+        match c {
+            '+' | '-' | '*' | '/' if !matches!(math.trim_end().chars().last(), Some('(' | ',' | '+' | '-' | '*' | '/')) => {
+                math.push(' ');
+                math.push(c);
+                math.push(' ');
+            },
+            _ => math.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
     }
+    math.split_whitespace().collect::<Vec<_>>().join(" ")
 }
@@ -7,6 +7,8 @@ impl Display for TailwindInstruction {
         }
         self.negative.write(f)?;
         match self.arbitrary.is_some() {
+            // arbitrary property, `[mask-type:luminance]`
+            true if self.elements.inner.is_empty() => write!(f, "{}", self.arbitrary.get_class()),
             true => write!(f, "{}-{}", self.elements, self.arbitrary.get_class()),
             false => write!(f, "{}", self.elements),
         }
@@ -16,7 +18,7 @@ impl Display for TailwindInstruction {
 impl TailwindInstance for TailwindInstruction {
     fn attributes(&self, ctx: &TailwindBuilder) -> CssAttributes {
         let mut out = CssAttributes::default();
-        match self.get_instance() {
+        match self.get_instance(ctx) {
             Ok(o) => {
                 out += o.attributes(ctx);
             },
@@ -7,6 +7,7 @@ impl<'a> From<AstStyle<'a>> for TailwindInstruction {
             variants: node.variants.into_iter().map(|s| s.into()).collect(),
             elements: TailwindElements { inner: node.elements.into_iter().map(|s| s.to_string()).collect() },
             arbitrary: TailwindArbitrary::from(node.arbitrary.unwrap_or_default()),
+            span: node.span,
         }
     }
 }
@@ -22,12 +23,94 @@ impl TailwindInstruction {
     pub fn view_elements(&self) -> Vec<&str> {
         self.elements.inner.iter().map(|s| s.as_str()).collect()
     }
+    /// View elements after the class prefix is removed, `tw-mb-4` => `["mb", "4"]`
+    ///
+    /// Returns `None` if the elements do not start with the prefix.
+    pub fn view_elements_unprefixed(&self, prefix: &str) -> Option<Vec<&str>> {
+        let mut elements = self.view_elements();
+        if prefix.is_empty() {
+            return Some(elements);
+        }
+        // elements are joined by `-`, so the prefix may end inside an element or on a separator
+        let mut rest = prefix;
+        while !rest.is_empty() {
+            let head = *elements.first()?;
+            match rest.strip_prefix(head) {
+                Some("") => {
+                    rest = "";
+                    elements.remove(0);
+                },
+                Some(r) => {
+                    rest = r.strip_prefix('-')?;
+                    elements.remove(0);
+                },
+                None => {
+                    elements[0] = head.strip_prefix(rest)?;
+                    rest = "";
+                },
+            }
+        }
+        Some(elements)
+    }
     #[inline]
     pub fn view_arbitrary(&self) -> &TailwindArbitrary {
         &self.arbitrary
     }
-    // TODO
-    pub fn normalization(self) -> Self {
+    /// Byte range of the class in the parsed input
+    #[inline]
+    pub fn view_span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+    /// Sort variants into a canonical order, `md:hover:p-2` => `hover:md:p-2`
+    ///
+    /// Pseudo elements stay in place, `hover:before::` and `before::hover:` select different elements.
+    pub fn normalization(mut self) -> Self {
+        for run in self.variants.split_mut(|v| v.pseudo) {
+            run.sort_by_cached_key(|v| v.to_string());
+        }
         self
     }
 }
+
+impl TailwindInstruction {
+    #[inline]
+    pub(crate) fn view_variants(&self) -> &[TailwindVariant] {
+        &self.variants
+    }
+}
+
+impl TailwindVariant {
+    /// `@media` condition of screen, color scheme and motion variants, `md` => `(min-width: 768px)`
+    pub(crate) fn get_media(&self, ctx: &TailwindBuilder) -> Option<String> {
+        let name = self.names.join("-");
+        if let Ok(width) = ctx.screens.try_get_width(&name) {
+            return Some(format!("(min-width: {}px)", width));
+        }
+        let query = match name.as_str() {
+            "dark" => "(prefers-color-scheme: dark)",
+            "motion-reduce" => "(prefers-reduced-motion: reduce)",
+            "motion-safe" => "(prefers-reduced-motion: no-preference)",
+            "print" => "print",
+            _ => return None,
+        };
+        Some(query.to_string())
+    }
+    /// Selector suffix of pseudo class and pseudo element variants, `hover` => `:hover`
+    pub(crate) fn get_selector(&self) -> String {
+        let name = self.names.join("-");
+        if self.pseudo {
+            return format!("::{}", name);
+        }
+        let class = match name.as_str() {
+            "first" => "first-child",
+            "last" => "last-child",
+            "odd" => "nth-child(odd)",
+            "even" => "nth-child(even)",
+            s => s,
+        };
+        match self.not {
+            true => format!(":not(:{})", class),
+            false => format!(":{}", class),
+        }
+    }
+}
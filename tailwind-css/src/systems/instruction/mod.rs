@@ -7,6 +7,7 @@ use crate::{TailwindBuilder, *};
 use css_color::Srgb;
 use std::{
     fmt::{Debug, Display, Formatter},
+    ops::Range,
     str::FromStr,
 };
 use tailwind_ast::{parse_fraction, ASTVariant, AstStyle};
@@ -18,6 +19,8 @@ pub struct TailwindInstruction {
     variants: Vec<TailwindVariant>,
     elements: TailwindElements,
     arbitrary: TailwindArbitrary,
+    /// Byte range of the class in the parsed input
+    span: Range<usize>,
 }
 
 #[derive(Debug, Clone)]
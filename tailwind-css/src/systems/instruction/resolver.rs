@@ -2,13 +2,47 @@ use super::*;
 
 // noinspection SpellCheckingInspection
 impl TailwindInstruction {
+    /// Resolve the utility, errors point at the class with [`TailwindError::range`]
+    pub fn get_instance(&self, ctx: &TailwindBuilder) -> Result<Box<dyn TailwindInstance>> {
+        self.resolve(ctx).map_err(|mut e| {
+            e.set_range(self.span.start, self.span.end);
+            e
+        })
+    }
     #[inline(never)]
-    pub fn get_instance(&self) -> Result<Box<dyn TailwindInstance>> {
-        let element = self.view_elements();
+    fn resolve(&self, ctx: &TailwindBuilder) -> Result<Box<dyn TailwindInstance>> {
+        let element = match self.view_elements_unprefixed(&ctx.prefix) {
+            Some(s) => s,
+            None => return syntax_error!("Missing prefix `{}`: {}", ctx.prefix, self),
+        };
         let pattern = element.as_slice();
-        let arbitrary = self.view_arbitrary();
+        let arbitrary = &self.view_arbitrary().resolve_theme(ctx)?;
+        let shortcut = match arbitrary.is_none() {
+            true => ctx.shortcuts.get(&pattern.join("-")),
+            false => None,
+        };
+        let instance = match shortcut {
+            Some(classes) => TailwindShortcut::parse(&pattern.join("-"), &classes, ctx)?.boxed(),
+            None => match ctx.plugins.get_instance_before(pattern, arbitrary, self.negative, ctx) {
+                Some(s) => s,
+                None => match self.get_builtin(pattern, arbitrary, ctx) {
+                    Ok(o) => o,
+                    Err(e) => ctx.plugins.get_instance_after(pattern, arbitrary, self.negative, ctx).ok_or(e)?,
+                },
+            },
+        };
+        // https://github.com/tailwindlabs/tailwindcss-typography#element-modifiers
+        match self.variants.iter().find(|v| v.names.first().map(|s| s.as_str()) == Some("prose")) {
+            Some(v) => Ok(TailwindProseElement::parse(&v.names[1..], instance)?.boxed()),
+            None => Ok(instance),
+        }
+    }
+    #[inline(never)]
+    fn get_builtin(&self, pattern: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Box<dyn TailwindInstance>> {
         let neg = self.negative;
         let instance = match pattern {
+            // https://tailwindcss.com/docs/adding-custom-styles#arbitrary-properties
+            [] if arbitrary.as_raw().contains(':') => TailwindArbitraryProperty::parse(arbitrary)?.boxed(),
             // Layout System
             ["aspect", rest @ ..] => TailwindAspect::parse(rest, arbitrary)?.boxed(),
             ["container"] => TailwindContainer::default().boxed(),
@@ -45,6 +79,8 @@ impl TailwindInstruction {
             ["right", rest @ ..] => TailwindRight::parse(rest, arbitrary, neg)?.boxed(),
             ["bottom", rest @ ..] => TailwindBottom::parse(rest, arbitrary, neg)?.boxed(),
             ["left", rest @ ..] => TailwindLeft::parse(rest, arbitrary, neg)?.boxed(),
+            ["start", rest @ ..] => TailwindStart::parse(rest, arbitrary, neg)?.boxed(),
+            ["end", rest @ ..] => TailwindEnd::parse(rest, arbitrary, neg)?.boxed(),
             // https://tailwindcss.com/docs/visibility
             ["invisible"] => TailwindVisibility::from("hidden").boxed(),
             ["visible" | "visibility", rest @ ..] => TailwindVisibility::parse(rest, arbitrary)?.boxed(),
@@ -69,19 +105,20 @@ impl TailwindInstruction {
             ["place", rest @ ..] => TailwindPlace::adapt(rest, arbitrary)?,
             // justify catched
             // Spacing System
-            ["p" | "pl" | "pr" | "pb" | "pt" | "px" | "py", ..] => TailwindPadding::parse(pattern, arbitrary, neg)?.boxed(),
-            ["m" | "ml" | "mr" | "mb" | "mt" | "mx" | "my", ..] => TailwindMargin::parse(pattern, arbitrary, neg)?.boxed(),
+            ["p" | "pl" | "pr" | "pb" | "pt" | "ps" | "pe" | "px" | "py", ..] => TailwindPadding::parse(pattern, arbitrary, neg)?.boxed(),
+            ["m" | "ml" | "mr" | "mb" | "mt" | "ms" | "me" | "mx" | "my", ..] => TailwindMargin::parse(pattern, arbitrary, neg)?.boxed(),
             ["space", rest @ ..] => TailwindSpace::parse(rest, arbitrary, neg)?,
             // Sizing System
             ["w", rest @ ..] => TailwindSizing::parse_width(rest, arbitrary)?.boxed(),
             ["min", "w", rest @ ..] => TailwindSizing::parse_width_min(rest, arbitrary)?.boxed(),
-            ["max", "w", rest @ ..] => TailwindSizing::parse_width_max(rest, arbitrary)?.boxed(),
-            ["h", rest @ ..] => TailwindSizing::parse_width(rest, arbitrary)?.boxed(),
-            ["min", "h", rest @ ..] => TailwindSizing::parse_width_min(rest, arbitrary)?.boxed(),
-            ["max", "h", rest @ ..] => TailwindSizing::parse_width_max(rest, arbitrary)?.boxed(),
+            ["max", "w", rest @ ..] => TailwindSizing::parse_width_max(rest, arbitrary, ctx)?.boxed(),
+            ["h", rest @ ..] => TailwindSizing::parse_height(rest, arbitrary)?.boxed(),
+            ["min", "h", rest @ ..] => TailwindSizing::parse_height_min(rest, arbitrary)?.boxed(),
+            ["max", "h", rest @ ..] => TailwindSizing::parse_height_max(rest, arbitrary)?.boxed(),
+            ["size", rest @ ..] => TailwindSizing::parse_size(rest, arbitrary)?.boxed(),
             // Typography System
             ["font", rest @ ..] => font_adaptor(rest, arbitrary)?,
-            ["text", rest @ ..] => text_adaptor(rest, arbitrary)?,
+            ["text", rest @ ..] => text_adaptor(rest, arbitrary, ctx)?,
             // begin https://tailwindcss.com/docs/font-variant-numeric
             ["antialiased"] => TailwindFontSmoothing::from("todo").boxed(),
             ["subpixel", "antialiased"] => TailwindFontSmoothing::from("todo").boxed(),
@@ -100,6 +137,8 @@ impl TailwindInstruction {
             // https://tailwindcss.com/docs/letter-spacing
             ["tracking", rest @ ..] => TailwindTracking::parse(rest, arbitrary)?.boxed(),
             ["leading", rest @ ..] => TailwindLeading::parse(rest, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/line-clamp
+            ["line", "clamp", rest @ ..] => TailwindLineClamp::parse(rest, arbitrary)?.boxed(),
             ["list", rest @ ..] => list_adaptor(rest, arbitrary)?,
             // https://tailwindcss.com/docs/text-decoration
             ["underline"] => TailwindDecorationLine::from("underline").boxed(),
@@ -122,7 +161,7 @@ impl TailwindInstruction {
             // break catched
             // content catched
             // Typography System Extension
-            ["prose"] => todo!(),
+            ["prose", rest @ ..] => TailwindProse::parse(rest, arbitrary)?.boxed(),
             // Backgrounds System
             ["bg", rest @ ..] => Self::bg_adaptor(rest, arbitrary)?,
             ["from", rest @ ..] => TailwindFrom::parse(rest, arbitrary)?.boxed(),
@@ -135,14 +174,14 @@ impl TailwindInstruction {
             ["outline", rest @ ..] => outline_adaptor(rest, arbitrary)?,
             ["ring", rest @ ..] => TailwindRing::adapt(rest, arbitrary)?,
             // Effects System
-            ["shadow", rest @ ..] => Self::shadow_adaptor(rest, arbitrary)?,
+            ["shadow", rest @ ..] => Self::shadow_adaptor(rest, arbitrary, ctx)?,
             ["opacity", rest @ ..] => TailwindOpacity::parse(rest, arbitrary, false)?.boxed(),
             ["mix", "blend", rest @ ..] => TailwindBlend::parse(rest, arbitrary)?.boxed(),
             // Filters System
             ["blur", rest @ ..] => TailwindBlur::parse(rest, arbitrary, false)?.boxed(),
             ["brightness", rest @ ..] => TailwindBrightness::parse(rest, arbitrary, false)?.boxed(),
             ["contrast", rest @ ..] => TailwindContrast::parse(rest, arbitrary, false)?.boxed(),
-            ["drop", "shadow", rest @ ..] => TailwindShadow::parse(rest, arbitrary, true)?.boxed(),
+            ["drop", "shadow", rest @ ..] => TailwindShadow::parse(rest, arbitrary, true, ctx)?.boxed(),
             ["grayscale", rest @ ..] => TailwindGrayscale::parse(rest, arbitrary, false)?.boxed(),
             ["hue", "rotate", rest @ ..] => TailwindHueRotate::parse(rest, arbitrary, false, neg)?.boxed(),
             ["invert", rest @ ..] => TailwindInvert::parse(rest, arbitrary, false)?.boxed(),
@@ -158,11 +197,15 @@ impl TailwindInstruction {
             ["delay", rest @ ..] => TailwindDelay::parse(rest, arbitrary)?.boxed(),
             ["animate", rest @ ..] => TailwindAnimate::parse(rest, arbitrary)?.boxed(),
             // Transforms System
+            ["transform", rest @ ..] => TailwindTransform::parse(rest, arbitrary)?.boxed(),
             ["scale", rest @ ..] => TailwindScale::parse(rest, arbitrary, neg)?.boxed(),
             ["rotate", rest @ ..] => TailwindRotate::parse(rest, arbitrary, neg)?.boxed(),
             ["translate", rest @ ..] => TailwindTranslate::parse(rest, arbitrary, neg)?.boxed(),
             ["skew", rest @ ..] => TailwindSkew::parse(rest, arbitrary, neg)?.boxed(),
             ["origin", rest @ ..] => TailwindOrigin::parse(rest, arbitrary)?.boxed(),
+            // Masks System
+            ["clip", rest @ ..] => TailwindClipPath::parse(rest, arbitrary)?.boxed(),
+            ["mask", rest @ ..] => Self::mask_adaptor(rest, arbitrary)?,
             // Interactivity System
             ["accent", rest @ ..] => TailwindAccentColor::parse(rest, arbitrary)?.boxed(),
             // https://tailwindcss.com/docs/appearance
@@ -183,7 +226,8 @@ impl TailwindInstruction {
             ["sr", "only"] => TailwindScreenReader::new(true).boxed(),
             ["not", "sr", "only"] => TailwindScreenReader::new(false).boxed(),
             // Form System Extension
-            _ => return syntax_error!("Unknown instructions: {} + {}", element.join("-"), arbitrary.get_class()),
+            ["form", rest @ ..] if ctx.forms.class => TailwindForm::parse(rest, arbitrary)?.boxed(),
+            _ => return syntax_error!("Unknown instructions: {} + {}", pattern.join("-"), arbitrary.get_class()),
         };
         Ok(instance)
     }
@@ -203,8 +247,21 @@ impl TailwindInstruction {
             // https://tailwindcss.com/docs/background-size
             [s @ ("auto" | "cover" | "contain")] => TailwindBackgroundSize::from(*s).boxed(),
             ["size", rest @ ..] => TailwindBackgroundSize::parse(rest, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/background-image
+            ["none"] => TailwindBackgroundImage::none().boxed(),
+            ["gradient", "to", rest @ ..] => TailwindBackgroundImage::parse(rest, arbitrary)?.boxed(),
+            ["radial", rest @ ..] => TailwindBackgroundImage::parse_radial(rest, arbitrary)?.boxed(),
+            ["conic", rest @ ..] => TailwindBackgroundImage::parse_conic(rest, arbitrary)?.boxed(),
             // https://tailwindcss.com/docs/background-blend-mode
             ["blend", rest @ ..] => TailwindBackgroundBlend::parse(rest, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/adding-custom-styles#resolving-ambiguities
+            [] => match arbitrary.get_hint() {
+                Some("url" | "image") => TailwindBackgroundImage::parse_arbitrary(arbitrary)?.boxed(),
+                None if arbitrary.as_str().starts_with("url(") => TailwindBackgroundImage::parse_arbitrary(arbitrary)?.boxed(),
+                Some("length" | "percentage") => TailwindBackgroundSize::parse(pattern, arbitrary)?.boxed(),
+                Some("position") => TailwindBackgroundPosition::parse(pattern, arbitrary)?.boxed(),
+                _ => TailwindBackgroundColor::parse(pattern, arbitrary)?.boxed(),
+            },
             _ => TailwindBackgroundColor::parse(pattern, arbitrary)?.boxed(),
         };
         Ok(out)
@@ -222,18 +279,48 @@ impl TailwindInstruction {
             // https://tailwindcss.com/docs/border-color
             ["black"] => color(TailwindColor::Black),
             ["white"] => color(TailwindColor::White),
-            _ => TailwindBorderColor::parse(pattern, arbitrary)?.boxed(),
+            // https://tailwindcss.com/docs/border-width
+            _ => match TailwindBorderWidth::parse(pattern, arbitrary) {
+                Ok(width) => width.boxed(),
+                Err(_) => TailwindBorderColor::parse(pattern, arbitrary)?.boxed(),
+            },
         };
         Ok(out)
     }
     #[inline]
-    fn shadow_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Box<dyn TailwindInstance>> {
+    fn shadow_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary, ctx: &TailwindBuilder) -> Result<Box<dyn TailwindInstance>> {
         let out = match pattern {
             // https://tailwindcss.com/docs/box-shadow
-            ["black" | "white" | "current" | "transparent"] => TailwindShadowColor::parse(pattern, arbitrary)?.boxed(),
-            ["color", rest @ ..] => TailwindShadowColor::parse(rest, arbitrary)?.boxed(),
+            [] if arbitrary.is_none() => TailwindShadow::parse(pattern, arbitrary, false, ctx)?.boxed(),
+            [] if arbitrary.as_color().is_err() => TailwindShadow::parse_arbitrary(arbitrary, false)?.boxed(),
+            _ if ctx.effects.has_box_shadow(&pattern.join("-")) => TailwindShadow::parse(pattern, arbitrary, false, ctx)?.boxed(),
             // https://tailwindcss.com/docs/box-shadow-color
-            _ => TailwindShadow::parse(pattern, arbitrary, false)?.boxed(),
+            ["color", rest @ ..] => TailwindShadowColor::parse(rest, arbitrary, false)?.boxed(),
+            _ => match TailwindShadowColor::parse(pattern, arbitrary, false) {
+                Ok(o) => o.boxed(),
+                Err(_) => TailwindShadow::parse(pattern, arbitrary, false, ctx)?.boxed(),
+            },
+        };
+        Ok(out)
+    }
+    #[inline]
+    fn mask_adaptor(pattern: &[&str], arbitrary: &TailwindArbitrary) -> Result<Box<dyn TailwindInstance>> {
+        let out = match pattern {
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/mask-image
+            [] => TailwindMaskImage::parse_arbitrary(arbitrary)?.boxed(),
+            ["none"] => TailwindMaskImage::none().boxed(),
+            ["image"] => TailwindMaskImage::parse_arbitrary(arbitrary)?.boxed(),
+            ["linear", "to", rest @ ..] => TailwindMaskImage::parse_linear(rest, arbitrary)?.boxed(),
+            ["radial", rest @ ..] => TailwindMaskImage::parse_radial(rest, arbitrary)?.boxed(),
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/mask-size
+            [s @ ("auto" | "cover" | "contain")] => TailwindMaskSize::from(*s).boxed(),
+            ["size", rest @ ..] => TailwindMaskSize::parse(rest, arbitrary)?.boxed(),
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/mask-repeat
+            ["no", "repeat"] => TailwindMaskRepeat::from("no-repeat").boxed(),
+            ["repeat", rest @ ..] => TailwindMaskRepeat::parse(rest, arbitrary)?.boxed(),
+            // https://developer.mozilla.org/en-US/docs/Web/CSS/mask-position
+            ["position", rest @ ..] => TailwindMaskPosition::parse(rest, arbitrary)?.boxed(),
+            _ => TailwindMaskPosition::parse(pattern, arbitrary)?.boxed(),
         };
         Ok(out)
     }
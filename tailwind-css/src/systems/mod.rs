@@ -4,11 +4,15 @@ mod colors;
 mod css_global;
 mod effect_system;
 mod font_system;
+mod form_system;
 mod instruction;
+mod plugin_system;
 mod preflight;
+mod safelist_system;
+mod shortcut_system;
 mod units;
 
 pub use self::{
-    breakpoints::*, builder::*, colors::*, css_global::*, effect_system::*, font_system::*, instruction::*, preflight::*,
-    units::*,
+    breakpoints::*, builder::*, colors::*, css_global::*, effect_system::*, font_system::*, form_system::*, instruction::*, plugin_system::*,
+    preflight::*, safelist_system::*, shortcut_system::*, units::*,
 };
@@ -0,0 +1,49 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{Negative, TailwindArbitrary, TailwindBuilder, TailwindInstance, TailwindPlugin};
+
+/// Custom utilities registered on the builder
+#[derive(Default)]
+pub struct PluginSystem {
+    /// Consulted before the builtin utilities, can override them
+    before: Vec<Box<dyn TailwindPlugin>>,
+    /// Consulted only when no builtin utility matches
+    after: Vec<Box<dyn TailwindPlugin>>,
+}
+
+impl Debug for PluginSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginSystem").field("before", &self.before.len()).field("after", &self.after.len()).finish()
+    }
+}
+
+impl PluginSystem {
+    /// Register a plugin that runs before the builtin utilities
+    pub fn register_before(&mut self, plugin: impl TailwindPlugin + 'static) {
+        self.before.push(Box::new(plugin))
+    }
+    /// Register a plugin that runs after the builtin utilities
+    pub fn register_after(&mut self, plugin: impl TailwindPlugin + 'static) {
+        self.after.push(Box::new(plugin))
+    }
+    /// First match of the plugins running before the builtin utilities
+    pub fn get_instance_before(
+        &self,
+        pattern: &[&str],
+        arbitrary: &TailwindArbitrary,
+        negative: Negative,
+        ctx: &TailwindBuilder,
+    ) -> Option<Box<dyn TailwindInstance>> {
+        self.before.iter().find_map(|p| p.get_instance(pattern, arbitrary, negative, ctx))
+    }
+    /// First match of the plugins running after the builtin utilities
+    pub fn get_instance_after(
+        &self,
+        pattern: &[&str],
+        arbitrary: &TailwindArbitrary,
+        negative: Negative,
+        ctx: &TailwindBuilder,
+    ) -> Option<Box<dyn TailwindInstance>> {
+        self.after.iter().find_map(|p| p.get_instance(pattern, arbitrary, negative, ctx))
+    }
+}
@@ -24,6 +24,18 @@ pub struct PreflightSystem {
     /// ## Buttons have a default outline
     /// To ensure that we provide accessible styles out of the box, we made sure that buttons have a default outline. You can of course override this by applying focus:ring or similar utilities to your buttons.
     pub button_outline: bool,
+    /// ## Gradient stops are reset globally
+    /// A `from-*` gradient fades to transparent unless a `to-*` is given, and the stops are not inherited from the parent.
+    pub reset_gradient: bool,
+    /// ## Transforms are reset globally
+    /// An element with `scale-110` does not take the `--tw-rotate` of a `rotate-45` parent.
+    pub reset_transform: bool,
+    /// ## Filters are reset globally
+    /// The `--tw-blur` of a parent does not leak into a child using `grayscale` or another filter, the same goes for backdrop filters.
+    pub reset_filter: bool,
+    /// ## Rings and shadows are reset globally
+    /// All of them compose into one `box-shadow`, so a `shadow-md` child must not pick up the `--tw-ring-shadow` of a `ring-2` parent.
+    pub reset_shadow: bool,
     /// Custom field for preflight
     pub custom: String,
 }
@@ -38,6 +50,10 @@ impl Default for PreflightSystem {
             block_level_image: true,
             unstyle_border: true,
             button_outline: true,
+            reset_gradient: true,
+            reset_transform: true,
+            reset_filter: true,
+            reset_shadow: true,
             custom: String::new(),
         }
     }
@@ -81,6 +97,61 @@ button:focus {
   outline: 1px dotted;
   outline: 5px auto -webkit-focus-ring-color;
 }
+"#;
+    const RESET_GRADIENT: &'static str = r#"
+*, ::before, ::after {
+  --tw-gradient-from-position: ;
+  --tw-gradient-via-position: ;
+  --tw-gradient-to-position: ;
+  --tw-gradient-to: transparent var(--tw-gradient-to-position,);
+}
+"#;
+    const RESET_TRANSFORM: &'static str = r#"
+*, ::before, ::after {
+  --tw-translate-x: 0;
+  --tw-translate-y: 0;
+  --tw-transform-gpu: ;
+  --tw-rotate: 0;
+  --tw-skew-x: 0;
+  --tw-skew-y: 0;
+  --tw-scale-x: 1;
+  --tw-scale-y: 1;
+}
+"#;
+    const RESET_FILTER: &'static str = r#"
+*, ::before, ::after {
+  --tw-blur: ;
+  --tw-brightness: ;
+  --tw-contrast: ;
+  --tw-grayscale: ;
+  --tw-hue-rotate: ;
+  --tw-invert: ;
+  --tw-saturate: ;
+  --tw-sepia: ;
+  --tw-drop-shadow: ;
+  --tw-backdrop-blur: ;
+  --tw-backdrop-brightness: ;
+  --tw-backdrop-contrast: ;
+  --tw-backdrop-grayscale: ;
+  --tw-backdrop-hue-rotate: ;
+  --tw-backdrop-invert: ;
+  --tw-backdrop-opacity: ;
+  --tw-backdrop-saturate: ;
+  --tw-backdrop-sepia: ;
+}
+"#;
+    const RESET_SHADOW: &'static str = r#"
+*, ::before, ::after {
+  --tw-ring-inset: ;
+  --tw-ring-offset-width: 0px;
+  --tw-ring-offset-color: #fff;
+  --tw-ring-color: rgb(59 130 246 / 0.5);
+  --tw-ring-offset-shadow: 0 0 #0000;
+  --tw-ring-shadow: 0 0 #0000;
+  --tw-shadow: 0 0 #0000;
+  --tw-shadow-color: initial;
+  --tw-text-shadow-color: initial;
+}
 "#;
 }
 
@@ -112,6 +183,22 @@ impl Display for PreflightSystem {
             f.write_str(Self::BUTTON_OUTLINE.trim())?;
             writeln!(f)?;
         }
+        if self.reset_gradient {
+            f.write_str(Self::RESET_GRADIENT.trim())?;
+            writeln!(f)?;
+        }
+        if self.reset_transform {
+            f.write_str(Self::RESET_TRANSFORM.trim())?;
+            writeln!(f)?;
+        }
+        if self.reset_filter {
+            f.write_str(Self::RESET_FILTER.trim())?;
+            writeln!(f)?;
+        }
+        if self.reset_shadow {
+            f.write_str(Self::RESET_SHADOW.trim())?;
+            writeln!(f)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use crate::{syntax_error, Result};
+
+mod pattern;
+
+/// Classes always emitted by the bundle, even if the content never mentions them
+///
+/// Useful for classes built at runtime, e.g. `format!("bg-{}-500", color)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SafelistSystem {
+    /// Explicit classes, an invalid one is an error
+    classes: BTreeSet<String>,
+    /// Classes generated from patterns, those that are not utilities are skipped
+    candidates: BTreeSet<String>,
+}
+
+impl SafelistSystem {
+    /// Safelist a single class, e.g. `bg-red-500` or `hover:underline`
+    pub fn insert(&mut self, class: impl Into<String>) {
+        self.classes.insert(class.into());
+    }
+    /// Safelist every class matched by the pattern, once plain and once with each variant
+    ///
+    /// The pattern must match the whole class and describe a finite set,
+    /// e.g. `bg-(red|green|blue)-(100|500)` with variants `["hover", "md"]`.
+    pub fn insert_pattern(&mut self, pattern: &str, variants: &[&str]) -> Result<()> {
+        let classes = match pattern::expand(pattern) {
+            Ok(o) => o,
+            Err(e) => return syntax_error!("Invalid safelist pattern `{}`: {}", pattern, e),
+        };
+        for class in classes {
+            for variant in variants {
+                self.candidates.insert(format!("{}:{}", variant, class));
+            }
+            self.candidates.insert(class);
+        }
+        Ok(())
+    }
+    /// Explicitly safelisted classes
+    pub fn get_classes(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|s| s.as_str())
+    }
+    /// Classes generated from patterns
+    pub fn get_candidates(&self) -> impl Iterator<Item = &str> {
+        self.candidates.iter().map(|s| s.as_str())
+    }
+}
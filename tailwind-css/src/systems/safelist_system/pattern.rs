@@ -0,0 +1,79 @@
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+/// Upper bound of classes a single pattern may generate
+const LIMIT: usize = 4096;
+
+/// All strings matched by the pattern
+pub(super) fn expand(pattern: &str) -> Result<Vec<String>, String> {
+    let hir = regex_syntax::parse(pattern).map_err(|e| e.to_string())?;
+    expand_hir(&hir)
+}
+
+fn expand_hir(hir: &Hir) -> Result<Vec<String>, String> {
+    let out = match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => vec![String::new()],
+        HirKind::Literal(literal) => vec![String::from_utf8_lossy(&literal.0).into_owned()],
+        HirKind::Class(Class::Unicode(class)) => {
+            let mut out = vec![];
+            for range in class.iter() {
+                check_size(out.len() + (range.end() as usize - range.start() as usize))?;
+                out.extend((range.start()..=range.end()).map(String::from));
+            }
+            out
+        },
+        HirKind::Class(Class::Bytes(class)) => {
+            let mut out = vec![];
+            for range in class.iter() {
+                out.extend((range.start()..=range.end()).map(|b| String::from(b as char)));
+            }
+            out
+        },
+        HirKind::Repetition(repeat) => {
+            let max = match repeat.max {
+                Some(s) => s,
+                None => return Err("unbounded repetition matches infinite classes".to_string()),
+            };
+            let sub = expand_hir(&repeat.sub)?;
+            let mut out = vec![];
+            let mut current = vec![String::new()];
+            for n in 0..=max {
+                if n >= repeat.min {
+                    out.extend(current.iter().cloned());
+                }
+                if n < max {
+                    current = product(&current, &sub)?;
+                }
+            }
+            out
+        },
+        HirKind::Capture(capture) => expand_hir(&capture.sub)?,
+        HirKind::Concat(items) => {
+            let mut out = vec![String::new()];
+            for item in items {
+                out = product(&out, &expand_hir(item)?)?;
+            }
+            out
+        },
+        HirKind::Alternation(items) => {
+            let mut out = vec![];
+            for item in items {
+                out.extend(expand_hir(item)?);
+            }
+            out
+        },
+    };
+    check_size(out.len())?;
+    Ok(out)
+}
+
+fn product(lhs: &[String], rhs: &[String]) -> Result<Vec<String>, String> {
+    check_size(lhs.len() * rhs.len())?;
+    Ok(lhs.iter().flat_map(|l| rhs.iter().map(move |r| format!("{}{}", l, r))).collect())
+}
+
+fn check_size(size: usize) -> Result<(), String> {
+    match size > LIMIT {
+        true => Err(format!("matches more than {} classes", LIMIT)),
+        false => Ok(()),
+    }
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+/// A shortcut expanded into one rule, see [`ShortcutSystem`]
+///
+/// Custom selectors such as `space-x-4` become a nested rule, additional css is rewritten against the shortcut.
+#[derive(Clone, Debug)]
+pub struct TailwindShortcut {
+    name: String,
+    attributes: CssAttributes,
+    /// Utilities with variants or combinators, grouped by `(media queries, selector suffix)`
+    variants: BTreeMap<(Vec<String>, String), CssAttributes>,
+    /// Additional css of the utilities, e.g. `@keyframes spin`
+    addition: String,
+}
+
+impl Display for TailwindShortcut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl TailwindInstance for TailwindShortcut {
+    fn inlineable(&self) -> bool {
+        // `:hover`, `@media` or `@keyframes` can not be written in the style attribute
+        self.variants.is_empty() && self.addition.is_empty()
+    }
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        self.attributes.clone()
+    }
+    fn additional(&self, ctx: &TailwindBuilder) -> String {
+        let mut out = self.addition.clone();
+        for ((media, suffix), attributes) in &self.variants {
+            let mut rule = format!(".{}{}{{{}}}", ctx.prefixed(&self.name), suffix, attributes);
+            for query in media.iter().rev() {
+                rule = format!("@media {}{{{}}}", query, rule);
+            }
+            out.push_str(&rule);
+        }
+        out
+    }
+}
+
+impl TailwindShortcut {
+    /// Expand `classes` into the rule of `name`
+    pub fn parse(name: &str, classes: &str, ctx: &TailwindBuilder) -> Result<Self> {
+        let mut out = Self::empty(name);
+        let mut stack = match ctx.shortcuts.get_entry(name) {
+            Some((pattern, _)) => vec![pattern.to_string()],
+            None => vec![],
+        };
+        out.expand(classes, ctx, &mut stack)?;
+        Ok(out)
+    }
+    /// Declarations for `@apply`, utilities with variants become nested rules
+    ///
+    /// `px-4 hover:underline` => `padding-left:1rem;padding-right:1rem;&:hover{text-decoration-line:underline;}`
+    pub(crate) fn get_nested(classes: &str, ctx: &TailwindBuilder) -> Result<String> {
+        let mut this = Self::empty("");
+        this.expand(classes, ctx, &mut vec![])?;
+        if !this.addition.is_empty() {
+            return syntax_error!("`@apply {}` needs additional css, which can not be nested", classes);
+        }
+        let mut out = this.attributes.to_string();
+        for ((media, suffix), attributes) in &this.variants {
+            let mut rule = match suffix.is_empty() {
+                true => attributes.to_string(),
+                false => format!("&{}{{{}}}", suffix, attributes),
+            };
+            for query in media.iter().rev() {
+                rule = format!("@media {}{{{}}}", query, rule);
+            }
+            out.push_str(&rule);
+        }
+        Ok(out)
+    }
+    fn empty(name: &str) -> Self {
+        Self { name: name.to_string(), attributes: Default::default(), variants: Default::default(), addition: String::new() }
+    }
+    /// `stack` holds the shortcuts being expanded, a shortcut met twice is a cycle
+    fn expand(&mut self, classes: &str, ctx: &TailwindBuilder, stack: &mut Vec<String>) -> Result<()> {
+        for item in parse_tailwind(classes)? {
+            let variants = item.view_variants();
+            let media: Vec<_> = variants.iter().filter_map(|v| v.get_media(ctx)).collect();
+            let suffix: String = variants.iter().filter(|v| v.get_media(ctx).is_none()).map(|v| v.get_selector()).collect();
+            let (attributes, addition, combinator) = match Self::get_shortcut(&item, ctx) {
+                Some((pattern, classes)) => {
+                    if stack.iter().any(|s| s == pattern) {
+                        return syntax_error!("Shortcut `{}` refers to itself: {} -> {}", pattern, stack.join(" -> "), pattern);
+                    }
+                    stack.push(pattern.to_string());
+                    let mut inner = Self::empty(&self.name);
+                    inner.expand(&classes, ctx, stack)?;
+                    stack.pop();
+                    for ((inner_media, inner_suffix), attributes) in inner.variants {
+                        let media = media.iter().cloned().chain(inner_media).collect();
+                        self.insert(media, format!("{}{}", suffix, inner_suffix), attributes);
+                    }
+                    (inner.attributes, inner.addition, String::new())
+                },
+                None => {
+                    let instance = item.get_instance(ctx)?;
+                    let addition = instance
+                        .additional(ctx)
+                        .replace(&format!(".{}", ctx.prefixed(&instance.id())), &format!(".{}", ctx.prefixed(&self.name)));
+                    // `space-x-4` => `.stack>:not([hidden])~:not([hidden])`
+                    (instance.attributes(ctx), addition, CssInstance::get_combinator(&*instance, ctx))
+                },
+            };
+            let plain = media.is_empty() && suffix.is_empty();
+            if !plain && !addition.is_empty() {
+                return syntax_error!("`{}` needs additional css, which can not be used with variants in a shortcut", item);
+            }
+            self.addition.push_str(&addition);
+            self.insert(media, suffix + &combinator, attributes);
+        }
+        Ok(())
+    }
+    fn insert(&mut self, media: Vec<String>, suffix: String, attributes: CssAttributes) {
+        match media.is_empty() && suffix.is_empty() {
+            true => self.attributes += attributes,
+            false => *self.variants.entry((media, suffix)).or_default() += attributes,
+        }
+    }
+    /// The shortcut a nested class refers to, resolved the same way as a top level class
+    fn get_shortcut<'a>(item: &TailwindInstruction, ctx: &'a TailwindBuilder) -> Option<(&'a str, String)> {
+        if item.view_arbitrary().is_some() {
+            return None;
+        }
+        let elements = item.view_elements_unprefixed(&ctx.prefix)?;
+        ctx.shortcuts.get_entry(&elements.join("-"))
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+};
+
+use crate::{parse_tailwind, syntax_error, CssAttributes, CssInstance, Result, TailwindBuilder, TailwindInstance, TailwindInstruction};
+
+pub use self::instance::TailwindShortcut;
+
+mod instance;
+
+/// Classes composed from other utilities, e.g. `btn = "px-4 py-2 rounded-md"`
+///
+/// A `{param}` in the name matches any rest of the class and is substituted into the utilities,
+/// `btn-{color} = "bg-{color}-600 text-white"` makes `btn-blue` expand to `bg-blue-600 text-white`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ShortcutSystem {
+    inner: BTreeMap<String, String>,
+}
+
+impl ShortcutSystem {
+    /// Register a shortcut, the later one overrides the former
+    pub fn insert<K, V>(&mut self, name: K, classes: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.inner.insert(name.into(), classes.into());
+    }
+    /// The utilities a class expands to, exact names take precedence over parameterized ones
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.get_entry(name).map(|(_, classes)| classes)
+    }
+    /// The registered name that matched along with the expanded utilities, `btn-{color}` for `btn-red`
+    pub(crate) fn get_entry(&self, name: &str) -> Option<(&str, String)> {
+        if let Some((pattern, s)) = self.inner.get_key_value(name) {
+            return Some((pattern, s.to_string()));
+        }
+        self.inner.iter().find_map(|(pattern, classes)| {
+            let (head, rest) = pattern.split_once('{')?;
+            let (param, tail) = rest.split_once('}')?;
+            let value = name.strip_prefix(head)?.strip_suffix(tail)?;
+            match value.is_empty() {
+                true => None,
+                false => Some((pattern.as_str(), classes.replace(&format!("{{{}}}", param), value))),
+            }
+        })
+    }
+    /// Load the `[shortcuts]` table of a theme config
+    ///
+    /// ```toml
+    /// [shortcuts]
+    /// btn = "px-4 py-2 rounded-md"
+    /// "btn-{color}" = "bg-{color}-600 text-white hover:bg-{color}-700"
+    /// ```
+    ///
+    /// Lines outside the table are ignored, a config without any table is read as a whole.
+    pub fn load(&mut self, config: &str) -> Result<()> {
+        let mut active = !config.lines().any(|line| line.trim_start().starts_with('['));
+        for line in config.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                active = table.trim() == "shortcuts";
+                continue;
+            }
+            if !active {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((name, classes)) => self.insert(unquote(name), unquote(classes)),
+                None => return syntax_error!("Invalid shortcut definition: {}", line),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    s.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(s)
+}
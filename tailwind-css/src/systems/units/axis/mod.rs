@@ -19,9 +19,32 @@ impl SpacingAxis {
         Self { class, attributes }
     }
 
+    /// Write the value to every longhand of the axis
+    ///
+    /// A value of several components, `py-[5px_5px]`, can not go to a single side, so it is written as the shorthand.
     pub fn write_attributes(&self, css: &mut CssAttributes, value: String) {
+        if let [first, _, ..] = self.attributes {
+            if let (Some((shorthand, _)), true) = (first.rsplit_once('-'), is_multiple(&value)) {
+                css.insert(shorthand.to_string(), value);
+                return;
+            }
+        }
         for attribute in self.attributes {
             css.insert(attribute.to_string(), value.to_string());
         }
     }
 }
+
+/// Whether the value has spaces outside of functions, `5px 5px` but not `calc(100% - 1rem)`
+fn is_multiple(value: &str) -> bool {
+    let mut depth = 0;
+    for c in value.trim().chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => return true,
+            _ => {},
+        }
+    }
+    false
+}
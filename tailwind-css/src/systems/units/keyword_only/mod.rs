@@ -29,10 +29,10 @@ impl StandardValue {
         }
         Ok(Self::Keyword(keyword))
     }
-    pub fn get_properties(&self) -> &str {
+    pub fn get_properties(&self) -> String {
         match self {
-            Self::Keyword(s) => s.as_str(),
-            Self::Arbitrary(s) => s.as_str(),
+            Self::Keyword(s) => s.to_string(),
+            Self::Arbitrary(s) => s.get_properties(),
         }
     }
     pub fn get_value(&self) -> &str {
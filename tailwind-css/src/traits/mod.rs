@@ -4,7 +4,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::{CssAttributes, TailwindBuilder};
+use crate::{CssAttributes, Negative, TailwindArbitrary, TailwindBuilder};
 
 pub mod instance;
 
@@ -38,3 +38,19 @@ pub trait TailwindInstance: Display {
         String::new()
     }
 }
+
+/// A custom utility provider, registered with [`TailwindBuilder::add_plugin`] or [`TailwindBuilder::add_fallback_plugin`]
+pub trait TailwindPlugin: Send + Sync {
+    /// Build the instance for a class, `None` if the class is not handled by this plugin
+    ///
+    /// - `pattern`: elements of the class with the prefix and the negative sign stripped, `["elevation", "2"]` for `elevation-2`
+    /// - `arbitrary`: the `[...]` part of the class, empty if missing
+    /// - `negative`: whether the class starts with `-`, as in `-elevation-2`
+    fn get_instance(
+        &self,
+        pattern: &[&str],
+        arbitrary: &TailwindArbitrary,
+        negative: Negative,
+        ctx: &TailwindBuilder,
+    ) -> Option<Box<dyn TailwindInstance>>;
+}
@@ -1,2 +1,3 @@
+mod support_css;
 #[cfg(feature = "html")]
 mod support_html;
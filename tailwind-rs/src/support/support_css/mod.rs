@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use tailwind_css::{find_closing, find_function, unquote, TailwindBuilder};
+use tailwind_error::TailwindError;
+
+use crate::{CLIConfig, Result};
+
+impl CLIConfig {
+    /// Compile an input stylesheet of the tailwind workflow
+    ///
+    /// - `@apply px-4 hover:underline;` expands into the declarations of the utilities
+    /// - `@layer base|components|utilities { ... }` moves the block to the matching `@tailwind` directive
+    /// - `@tailwind base|components|utilities;` inserts the preflight, the components layer and all traced utilities
+    /// - `theme('colors.red.500')` and `screen(md)` are resolved against the builder
+    ///
+    /// Classes must be traced before, otherwise `@tailwind utilities` is empty.
+    pub fn compile_input_css(&self, input: &str, tw: &TailwindBuilder) -> Result<String> {
+        let css = expand_apply(input, tw)?;
+        let (css, mut layers) = take_layers(&css)?;
+        let css = expand_tailwind(&css, tw, &mut layers)?;
+        let css = resolve_functions(&css, tw)?;
+        self.compile_css(&css)
+    }
+}
+
+/// `@apply px-4 py-2;`
+fn expand_apply(css: &str, tw: &TailwindBuilder) -> Result<String> {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("@apply") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + "@apply".len()..];
+        let end = match after.find([';', '}']) {
+            Some(s) => s,
+            None => return Err(TailwindError::syntax_error("Unterminated `@apply`")),
+        };
+        out.push_str(&tw.apply(after[..end].trim())?);
+        // the last declaration may omit the `;`
+        rest = after[end..].strip_prefix(';').unwrap_or(&after[end..]);
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// `@layer components { ... }`, other layers are left to the browser
+fn take_layers(css: &str) -> Result<(String, BTreeMap<String, String>)> {
+    let mut out = String::with_capacity(css.len());
+    let mut layers = BTreeMap::<String, String>::new();
+    let mut rest = css;
+    while let Some(start) = rest.find("@layer") {
+        let head = start + "@layer".len();
+        let name_end = match rest[head..].find(['{', ';']) {
+            Some(s) => head + s,
+            None => return Err(TailwindError::syntax_error("Unterminated `@layer`")),
+        };
+        let name = rest[head..name_end].trim();
+        if rest[name_end..].starts_with(';') || !matches!(name, "base" | "components" | "utilities") {
+            out.push_str(&rest[..head]);
+            rest = &rest[head..];
+            continue;
+        }
+        let close = find_closing(rest, name_end)?;
+        out.push_str(&rest[..start]);
+        layers.entry(name.to_string()).or_default().push_str(&rest[name_end + 1..close]);
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    Ok((out, layers))
+}
+
+/// `@tailwind utilities;`
+fn expand_tailwind(css: &str, tw: &TailwindBuilder, layers: &mut BTreeMap<String, String>) -> Result<String> {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("@tailwind") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + "@tailwind".len()..];
+        let end = match after.find(';') {
+            Some(s) => s,
+            None => return Err(TailwindError::syntax_error("Unterminated `@tailwind`")),
+        };
+        let name = after[..end].trim();
+        match name {
+            "base" => out.push_str(&tw.bundle_base()),
+            "utilities" => out.push_str(&tw.bundle_utilities()?),
+            "components" | "variants" | "screens" => {},
+            _ => return Err(TailwindError::syntax_error(format!("Unknown `@tailwind {}`", name))),
+        }
+        if let Some(layer) = layers.remove(name) {
+            out.push_str(&layer);
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    // layers without a directive stay at the end
+    for layer in layers.values() {
+        out.push_str(layer);
+    }
+    Ok(out)
+}
+
+/// `theme('colors.red.500')`, `theme(spacing.4, 1rem)` and `screen(md)`
+fn resolve_functions(css: &str, tw: &TailwindBuilder) -> Result<String> {
+    let css = tw.resolve_theme(css)?;
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css.as_str();
+    while let Some(start) = find_function(rest, "screen(") {
+        let open = start + "screen".len();
+        let close = find_closing(rest, open)?;
+        out.push_str(&rest[..start]);
+        out.push_str(&tw.get_screen(unquote(&rest[open + 1..close]))?);
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
@@ -16,6 +16,9 @@ impl CLIConfig {
             CssInlineMode::DataKey => HtmlConfig::keyed_all_class(input, tw)?,
             CssInlineMode::DataValue => HtmlConfig::value_all_class(input, tw)?,
         };
+        for e in tw.take_diagnostics() {
+            error!("{}", e)
+        }
         let bundle = tw.bundle()?;
         let css = self.compile_css(&bundle)?;
         Ok((html, css))
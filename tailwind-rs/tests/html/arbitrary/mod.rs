@@ -54,3 +54,18 @@ fn test_arbitrary_value() {
     assert_eq!(html, include_str!("arbitrary.value.html"));
     assert_eq!(css, include_str!("arbitrary.value.css"));
 }
+
+#[test]
+fn test_arbitrary_property_hint() {
+    let (_, mut builder) = pre_config();
+    assert_eq!(builder.trace("[color:red]", false).unwrap(), "[color:red]");
+    assert_eq!(builder.trace("[position:absolute]", false).unwrap(), "[position:absolute]");
+    assert_eq!(builder.trace("text-[color:red]", false).unwrap(), "text-[color:red]");
+}
+
+#[test]
+fn test_arbitrary_theme_sizing() {
+    let (_, mut builder) = pre_config();
+    assert_eq!(builder.trace("w-[calc(100%-theme(spacing.4))]", false).unwrap(), "w-[calc(100%-theme(spacing.4))]");
+    assert_eq!(builder.inline("w-[calc(100%-theme(spacing.4))]").unwrap().1, "width:calc(100% - 1rem);");
+}
@@ -21,3 +21,15 @@ fn test_border_inline() {
     assert_eq!(html, include_str!("border.inline.html"));
     assert_eq!(css, include_str!("border.inline.css"));
 }
+
+#[test]
+fn test_border_obfuscate() {
+    let (mut config, mut builder) = pre_config();
+    config.obfuscate = true;
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("border.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/border/border.obfuscate.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/border/border.obfuscate.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("border.obfuscate.html"));
+    assert_eq!(css, include_str!("border.obfuscate.css"));
+}
@@ -21,3 +21,31 @@ fn test_effect_inline() {
     assert_eq!(html, include_str!("effect.inline.html"));
     assert_eq!(css, include_str!("effect.inline.css"));
 }
+
+#[test]
+fn test_effect_preset_alpha() {
+    let (_, mut builder) = pre_config();
+    builder.effects.insert_box_shadow("soft", "0 1px 2px rgba(0, 0, 0, 0.3), 0 1px 3px hsl(0 0% 0% / 10%)");
+    builder.effects.insert_drop_shadow("soft", "0 1px 2px rgba(0, 0, 0, 0.3), 0 1px 1px rgb(0 0 0 / 6%)");
+    assert_eq!(
+        builder.effects.get_drop_shadow("soft").unwrap(),
+        "drop-shadow(0 1px 2px rgba(0, 0, 0, 0.3)) drop-shadow(0 1px 1px rgb(0 0 0 / 6%))"
+    );
+    let colored: Vec<_> = builder.effects.get_box_shadow_layers("soft").unwrap().iter().map(|s| s.get_colored("--tw-shadow-color")).collect();
+    assert_eq!(colored, [
+        "0 1px 2px color-mix(in srgb, var(--tw-shadow-color, rgba(0, 0, 0, 1)) 30%, transparent)",
+        "0 1px 3px color-mix(in srgb, var(--tw-shadow-color, rgba(0, 0, 0, 1)) 10%, transparent)",
+    ]);
+}
+
+#[test]
+fn test_effect_unknown_preset() {
+    let (_, mut builder) = pre_config();
+    assert!(builder.trace("shadow-foo", false).is_err());
+    assert!(builder.trace("drop-shadow-foo", false).is_err());
+    assert!(builder.trace("shadow-lg/50", false).is_err());
+    assert!(builder.trace("text-shadow-foo", false).is_err());
+    assert!(builder.effects.get_box_shadow("foo").is_err());
+    assert!(builder.resolve_theme("theme(boxShadow.foo)").is_err());
+    assert!(builder.trace("shadow-lg drop-shadow-md shadow-blue-500/50", false).is_ok());
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn test_forms_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.forms().class = true;
+    let (html, css) = config.compile_html(include_str!("forms.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/forms/forms.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/forms/forms.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("forms.traced.html"));
+    assert_eq!(css, include_str!("forms.traced.css"));
+}
+
+#[test]
+fn test_forms_base() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.forms().base = true;
+    let preflight = builder.preflight();
+    preflight.disable = false;
+    preflight.remove_margins = false;
+    preflight.unstyle_head = false;
+    preflight.unstyle_list = false;
+    preflight.block_level_image = false;
+    preflight.unstyle_border = false;
+    preflight.button_outline = false;
+    preflight.reset_gradient = false;
+    preflight.reset_transform = false;
+    preflight.reset_filter = false;
+    preflight.reset_shadow = false;
+    let (html, css) = config.compile_html(include_str!("forms.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/forms/forms.base.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/forms/forms.base.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("forms.base.html"));
+    assert_eq!(css, include_str!("forms.base.css"));
+}
@@ -54,3 +54,24 @@ fn test_grouped_value() {
     assert_eq!(html, include_str!("grouped.value.html"));
     assert_eq!(css, include_str!("grouped.value.css"));
 }
+
+#[test]
+fn test_grouped_error_range() {
+    let (_, mut builder) = pre_config();
+    let input = "p-2 hover:(m-1 unknown-class)";
+    let error = builder.trace(input, false).unwrap_err();
+    assert_eq!(error.range, Some(15..28));
+    assert_eq!(&input[15..28], "unknown-class");
+}
+
+#[test]
+fn test_grouped_recover() {
+    let (_, mut builder) = pre_config();
+    builder.recover = true;
+    let input = "p-2 ]oops hover:(m-1 unknown-class)";
+    let traced = builder.trace(input, false).unwrap();
+    assert_eq!(traced, "]oops hover:unknown-class m-1 p-2");
+    let ranges: Vec<_> = builder.take_diagnostics().into_iter().map(|e| e.range).collect();
+    assert_eq!(ranges, vec![Some(4..9), Some(21..34)]);
+    assert!(builder.bundle().unwrap().contains(".m-1"));
+}
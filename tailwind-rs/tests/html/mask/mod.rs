@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_mask_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("mask.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/mask/mask.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/mask/mask.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("mask.traced.html"));
+    assert_eq!(css, include_str!("mask.traced.css"));
+}
+
+#[test]
+fn test_mask_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("mask.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/mask/mask.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/mask/mask.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("mask.inline.html"));
+    assert_eq!(css, include_str!("mask.inline.css"));
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[test]
+fn test_merge_override() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["p-4 text-red-500", "p-2"]), "text-red-500 p-2");
+    assert_eq!(builder.merge_classes(&["text-red-500 text-lg", "text-blue-500"]), "text-lg text-blue-500");
+    assert_eq!(builder.merge_classes(&["m-2 -m-4"]), "-m-4");
+}
+
+#[test]
+fn test_merge_refinement() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["p-4", "px-2"]), "p-4 px-2");
+    assert_eq!(builder.merge_classes(&["px-2", "py-1"]), "px-2 py-1");
+    assert_eq!(builder.merge_classes(&["px-2 py-1", "p-4"]), "p-4");
+    assert_eq!(builder.merge_classes(&["leading-6", "text-lg"]), "text-lg");
+    assert_eq!(builder.merge_classes(&["text-lg", "leading-6"]), "text-lg leading-6");
+    assert_eq!(builder.merge_classes(&["text-lg", "leading-8"]), "text-lg leading-8");
+    assert_eq!(builder.merge_classes(&["rounded-lg", "rounded-t-none"]), "rounded-lg rounded-t-none");
+    assert_eq!(builder.merge_classes(&["inset-0", "top-2"]), "inset-0 top-2");
+}
+
+#[test]
+fn test_merge_composed() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["rotate-45", "scale-110"]), "rotate-45 scale-110");
+    assert_eq!(builder.merge_classes(&["translate-x-2", "translate-y-4"]), "translate-x-2 translate-y-4");
+    assert_eq!(builder.merge_classes(&["rotate-45", "rotate-90"]), "rotate-90");
+    assert_eq!(builder.merge_classes(&["shadow-md", "ring-2"]), "shadow-md ring-2");
+    assert_eq!(builder.merge_classes(&["shadow-md", "shadow-lg"]), "shadow-lg");
+}
+
+#[test]
+fn test_merge_variants() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["p-4 text-red-500 hover:p-4", "p-2 hover:p-2"]), "text-red-500 p-2 hover:p-2");
+    assert_eq!(builder.merge_classes(&["p-4 hover:p-4", "p-2"]), "hover:p-4 p-2");
+    assert_eq!(builder.merge_classes(&["md:hover:p-4", "hover:md:p-2"]), "hover:md:p-2");
+    assert_eq!(builder.merge_classes(&["hover:(p-4 m-4)", "hover:p-2"]), "hover:m-4 hover:p-2");
+    assert_eq!(builder.merge_classes(&["before::hover:p-4", "hover:before::p-2"]), "before::hover:p-4 hover:before::p-2");
+}
+
+#[test]
+fn test_merge_arbitrary() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["w-4", "w-[3.23rem]"]), "w-[3.23rem]");
+    assert_eq!(builder.merge_classes(&["text-[length:1rem] text-red-500", "text-[length:1.5rem]"]), "text-red-500 text-[length:1.5rem]");
+    assert_eq!(builder.merge_classes(&["text-lg", "text-[length:1.5rem]"]), "text-[length:1.5rem]");
+    assert_eq!(builder.merge_classes(&["[mask-type:luminance]", "[mask-type:alpha]"]), "[mask-type:alpha]");
+}
+
+#[test]
+fn test_merge_unknown() {
+    let (_, builder) = pre_config();
+    assert_eq!(builder.merge_classes(&["card ]oops p-4", "card p-2"]), "card ]oops card p-2");
+    assert_eq!(builder.merge_classes(&["space-x-2 mx-2", "mx-4"]), "space-x-2 mx-4");
+}
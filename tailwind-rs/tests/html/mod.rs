@@ -8,11 +8,20 @@ mod border;
 mod effect;
 mod filter;
 mod flex;
+mod forms;
 mod grouped;
 mod interactivity;
 mod layout;
+mod mask;
+mod merge;
+mod plugin;
+mod prefix;
+mod prose;
+mod safelist;
+mod shortcut;
 mod sizing;
 mod spacing;
+mod stylesheet;
 mod svg;
 mod table;
 mod transform;
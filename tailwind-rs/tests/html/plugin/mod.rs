@@ -0,0 +1,101 @@
+use super::*;
+use std::fmt::{Display, Formatter};
+use tailwind_css::{css_attributes, CssAttributes, Negative, TailwindArbitrary, TailwindInstance, TailwindPlugin};
+
+/// `elevation-2`, `-elevation-2` sinks the element with an inset shadow
+#[derive(Debug)]
+struct Elevation(u32, Negative);
+
+impl Display for Elevation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.1.write(f)?;
+        write!(f, "elevation-{}", self.0)
+    }
+}
+
+impl TailwindInstance for Elevation {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        let inset = if self.1 == true { "inset " } else { "" };
+        css_attributes! {
+            "box-shadow" => format!("{}0 {}px {}px rgb(0 0 0 / 0.2)", inset, self.0, self.0 * 2)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ElevationPlugin;
+
+impl TailwindPlugin for ElevationPlugin {
+    fn get_instance(&self, pattern: &[&str], _: &TailwindArbitrary, negative: Negative, _: &TailwindBuilder) -> Option<Box<dyn TailwindInstance>> {
+        match pattern {
+            ["elevation", n] => Some(Elevation(n.parse().ok()?, negative).boxed()),
+            _ => None,
+        }
+    }
+}
+
+/// `brand-*`, only reached when no builtin utility matches
+#[derive(Debug)]
+struct Brand(String);
+
+impl Display for Brand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "brand-{}", self.0)
+    }
+}
+
+impl TailwindInstance for Brand {
+    fn attributes(&self, _: &TailwindBuilder) -> CssAttributes {
+        css_attributes! {
+            "background-image" => format!("url(/icons/{}.svg)", self.0)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BrandPlugin;
+
+impl TailwindPlugin for BrandPlugin {
+    fn get_instance(&self, pattern: &[&str], _: &TailwindArbitrary, _: Negative, _: &TailwindBuilder) -> Option<Box<dyn TailwindInstance>> {
+        match pattern {
+            ["brand", name] => Some(Brand(name.to_string()).boxed()),
+            _ => None,
+        }
+    }
+}
+
+fn plugin_config() -> (CLIConfig, TailwindBuilder) {
+    let (config, mut builder) = pre_config();
+    builder.add_plugin(ElevationPlugin);
+    builder.add_fallback_plugin(BrandPlugin);
+    (config, builder)
+}
+
+#[test]
+fn test_plugin_trace() {
+    let (mut config, mut builder) = plugin_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("plugin.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/plugin/plugin.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/plugin/plugin.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("plugin.traced.html"));
+    assert_eq!(css, include_str!("plugin.traced.css"));
+}
+
+#[test]
+fn test_plugin_inline() {
+    let (mut config, mut builder) = plugin_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("plugin.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/plugin/plugin.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/plugin/plugin.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("plugin.inline.html"));
+    assert_eq!(css, include_str!("plugin.inline.css"));
+}
+
+#[test]
+fn test_plugin_send() {
+    fn assert_send<T: Send + Sync>(_: &T) {}
+    let (_, builder) = plugin_config();
+    assert_send(&builder);
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn test_prefix_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    builder.set_prefix("tw-");
+    let (html, css) = config.compile_html(include_str!("prefix.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/prefix/prefix.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/prefix/prefix.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("prefix.traced.html"));
+    assert_eq!(css, include_str!("prefix.traced.css"));
+}
+
+#[test]
+fn test_prefix_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    builder.set_prefix("tw-");
+    let (html, css) = config.compile_html(include_str!("prefix.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/prefix/prefix.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/prefix/prefix.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("prefix.inline.html"));
+    assert_eq!(css, include_str!("prefix.inline.css"));
+}
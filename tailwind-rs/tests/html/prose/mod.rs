@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_prose_trace() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("prose.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/prose/prose.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/prose/prose.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("prose.traced.html"));
+    assert_eq!(css, include_str!("prose.traced.css"));
+}
+
+#[test]
+fn test_prose_inline() {
+    let (mut config, mut builder) = pre_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("prose.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/prose/prose.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/prose/prose.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("prose.inline.html"));
+    assert_eq!(css, include_str!("prose.inline.css"));
+}
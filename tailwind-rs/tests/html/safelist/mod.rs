@@ -0,0 +1,29 @@
+use super::*;
+
+fn safelist_config() -> (CLIConfig, TailwindBuilder) {
+    let (config, mut builder) = pre_config();
+    builder.add_safelist("text-center");
+    builder.add_safelist_pattern("bg-(red|blue)-(100|500)", &["hover"]).unwrap();
+    builder.add_safelist_pattern("m[xy]?-[1-2]", &[]).unwrap();
+    builder.add_blocklist("container");
+    builder.add_blocklist("font-bold");
+    (config, builder)
+}
+
+#[test]
+fn test_safelist_trace() {
+    let (mut config, mut builder) = safelist_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("safelist.html"), &mut builder).unwrap();
+    // std::fs::write("tests/html/safelist/safelist.traced.html", html.as_bytes()).unwrap();
+    // std::fs::write("tests/html/safelist/safelist.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("safelist.traced.html"));
+    assert_eq!(css, include_str!("safelist.traced.css"));
+}
+
+#[test]
+fn test_safelist_pattern() {
+    let mut builder = TailwindBuilder::default();
+    assert!(builder.add_safelist_pattern("bg-.*", &[]).is_err());
+    assert!(builder.add_safelist_pattern("bg-(red", &[]).is_err());
+}
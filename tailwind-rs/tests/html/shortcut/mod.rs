@@ -0,0 +1,48 @@
+use super::*;
+
+fn shortcut_config() -> (CLIConfig, TailwindBuilder) {
+    let (config, mut builder) = pre_config();
+    builder.shortcuts.load(include_str!("shortcut.toml")).unwrap();
+    builder.add_shortcut("center", "flex items-center justify-center");
+    (config, builder)
+}
+
+#[test]
+fn test_shortcut_trace() {
+    let (mut config, mut builder) = shortcut_config();
+    config.mode = CssInlineMode::None;
+    let (html, css) = config.compile_html(include_str!("shortcut.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/shortcut/shortcut.traced.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/shortcut/shortcut.traced.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("shortcut.traced.html"));
+    assert_eq!(css, include_str!("shortcut.traced.css"));
+}
+
+#[test]
+fn test_shortcut_inline() {
+    let (mut config, mut builder) = shortcut_config();
+    config.mode = CssInlineMode::Inline;
+    let (html, css) = config.compile_html(include_str!("shortcut.html"), &mut builder).unwrap();
+    std::fs::write("tests/html/shortcut/shortcut.inline.html", html.as_bytes()).unwrap();
+    std::fs::write("tests/html/shortcut/shortcut.inline.css", css.as_bytes()).unwrap();
+    assert_eq!(html, include_str!("shortcut.inline.html"));
+    assert_eq!(css, include_str!("shortcut.inline.css"));
+}
+
+#[test]
+fn test_shortcut_cycle() {
+    let (_, mut builder) = pre_config();
+    builder.add_shortcut("a", "p-2 b");
+    builder.add_shortcut("b", "a");
+    builder.add_shortcut("x-{c}", "x-{c}-y");
+    assert!(builder.trace("a", false).is_err());
+    assert!(builder.trace("x-1", false).is_err());
+    assert!(builder.apply("b").is_err());
+}
+
+#[test]
+fn test_shortcut_additional() {
+    let (_, mut builder) = pre_config();
+    builder.add_shortcut("hover-wrapper", "hover:container");
+    assert!(builder.trace("hover-wrapper", false).is_err());
+}
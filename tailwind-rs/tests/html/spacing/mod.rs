@@ -21,3 +21,11 @@ fn test_spacing_inline() {
     assert_eq!(html, include_str!("spacing.inline.html"));
     assert_eq!(css, include_str!("spacing.inline.css"));
 }
+
+#[test]
+fn test_space_negative_keyword() {
+    let (_, mut builder) = pre_config();
+    assert!(builder.trace("-space-x-auto", false).is_err());
+    assert!(builder.trace("-space-y-unset", false).is_err());
+    assert!(builder.trace("-space-x-2 space-x-auto", false).is_ok());
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+#[test]
+fn test_stylesheet() {
+    let (config, mut builder) = pre_config();
+    config.compile_html(include_str!("stylesheet.html"), &mut builder).unwrap();
+    let css = config.compile_input_css(include_str!("stylesheet.css"), &builder).unwrap();
+    // std::fs::write("tests/html/stylesheet/stylesheet.output.css", css.as_bytes()).unwrap();
+    assert_eq!(css, include_str!("stylesheet.output.css"));
+}